@@ -51,9 +51,6 @@ fn setup(
 			..default()
 		},
 		RigidBody::Dynamic,
-		// IMPORTANT: parent's external force must be non-persistent
-		// so that each frame this library can update it just after it resets
-		ExternalForce::ZERO.with_persistence(false),
 		// doesn't really matter what actual computed collider you chose
 		// you could just add manual collider as well
 		Collider::cuboid(1.0, 1.0, 1.0),
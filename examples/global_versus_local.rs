@@ -1,4 +1,4 @@
-//! Shows the difference between an [InternalForce::Global] and [InternalForce::Local].
+//! Shows the difference between an [InternalFrame::World] and [InternalFrame::ChildLocal] [InternalForce].
 //! 
 //! The spinning of the structure is erratic, since one child is in global space (down) for its
 //! [InternalForce] and the other is in local space (right and up).
@@ -50,9 +50,6 @@ fn setup(
 			..default()
 		},
 		RigidBody::Dynamic,
-		// IMPORTANT: parent's external force must be non-persistent
-		// so that each frame this library can update it just after it resets
-		ExternalForce::ZERO.with_persistence(false),
 		// doesn't really matter what actual computed collider you chose
 		// you could just add manual collider as well
 		AsyncCollider(ComputedCollider::ConvexHull),
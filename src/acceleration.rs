@@ -0,0 +1,144 @@
+//! Children specified by the acceleration they give their parent, rather than by a force.
+
+use serde::{Deserialize, Serialize};
+
+use crate::frame::EntityFrames;
+use crate::gimbal::gimballed_rotation;
+use crate::hierarchy::RigidBodyAncestors;
+use crate::prelude::*;
+
+/// A child that accelerates its [RigidBody] ancestor by a fixed amount, however heavy the parent is,
+/// e.g. a booster that always gives `2` meters per second squared.
+///
+/// Every time [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs,
+/// the linear acceleration is multiplied by the parent's current [Mass] and written into the child's [InternalForce],
+/// and the angular acceleration is multiplied by the parent's (world space) [Inertia] and written into its [InternalTorque].
+/// Both are inserted automatically if missing, and their strengths are left alone, so they still scale the acceleration.
+///
+/// Like any [InternalForce], the force is applied at the child's position, so a child that isn't at the parent's
+/// [CenterOfMass] also spins it. The gyroscopic effect of a spinning parent isn't compensated for,
+/// so several children can add their accelerations together.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d_parenting::prelude::*;
+///
+/// // 2 m/s^2 forwards and 0.5 rad/s^2 of yaw, in the space of the parent
+/// let booster = InternalAcceleration::new_parent_body(Vec3::NEG_Z * 2.0, Vec3::Y * 0.5);
+/// ```
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct InternalAcceleration {
+	/// In meters per second squared, in the space of [InternalAcceleration::frame]
+	pub linear: Vec3,
+	/// In radians per second squared, in the space of [InternalAcceleration::frame]
+	pub angular: Vec3,
+	/// The frame of reference that both accelerations are specified in
+	pub frame: InternalFrame,
+}
+
+impl InternalAcceleration {
+	pub const ZERO: Self = InternalAcceleration {
+		linear: Vec3::ZERO,
+		angular: Vec3::ZERO,
+		frame: InternalFrame::ChildLocal,
+	};
+
+	pub fn new_in_frame(frame: InternalFrame, linear: Vec3, angular: Vec3) -> Self {
+		Self {
+			linear,
+			angular,
+			frame,
+		}
+	}
+
+	/// In the space of the child, see [InternalFrame::ChildLocal]
+	pub fn new_local(linear: Vec3, angular: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::ChildLocal, linear, angular)
+	}
+
+	/// In the space of the parent, see [InternalFrame::ParentBody]
+	pub fn new_parent_body(linear: Vec3, angular: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::ParentBody, linear, angular)
+	}
+
+	/// In world space, see [InternalFrame::World]
+	pub fn new_global(linear: Vec3, angular: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::World, linear, angular)
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts an [InternalForce] and [InternalTorque] on every [InternalAcceleration] that doesn't have them yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_internal_accelerations(
+		mut commands: Commands,
+		accelerations: Query<
+			(
+				Entity,
+				&InternalAcceleration,
+				Has<InternalForce>,
+				Has<InternalTorque>,
+			),
+			Or<(Without<InternalForce>, Without<InternalTorque>)>,
+		>,
+	) {
+		for (entity, acceleration, has_force, has_torque) in accelerations.iter() {
+			let mut entity_commands = commands.entity(entity);
+			if !has_force {
+				entity_commands.insert(InternalForce::new_in_frame(acceleration.frame, Vec3::ZERO));
+			}
+			if !has_torque {
+				entity_commands.insert(InternalTorque::new_global(Vec3::ZERO));
+			}
+		}
+	}
+
+	/// Scales every [InternalAcceleration] by its parent's [Mass] and [Inertia],
+	/// writing the results into its [InternalForce] and [InternalTorque].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn update_internal_accelerations(
+		mut accelerations: Query<
+			(
+				Entity,
+				&InternalAcceleration,
+				Option<&Gimbal>,
+				&mut InternalForce,
+				&mut InternalTorque,
+			),
+			Without<RigidBody>,
+		>,
+		parents: Query<(&Mass, &Inertia, &Rotation), With<RigidBody>>,
+		ancestors: RigidBodyAncestors,
+		entity_frames: EntityFrames,
+	) {
+		for (child, acceleration, gimbal, mut internal_force, mut internal_torque) in
+			accelerations.iter_mut()
+		{
+			let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+				// reported by `diagnose`
+				continue;
+			};
+			let Ok((mass, inertia, rotation)) = parents.get(rigid_body) else {
+				continue;
+			};
+
+			internal_force.force = acceleration.linear * mass.0;
+			internal_force.frame = acceleration.frame;
+
+			let Some(angular) = acceleration.frame.to_world(
+				acceleration.angular,
+				rotation.0,
+				gimballed_rotation(child_relative_transform.rotation, gimbal),
+				|entity| entity_frames.rotation(entity),
+			) else {
+				// reported by `diagnose`, and the previous torque would otherwise keep being applied in world space
+				internal_force.force = Vec3::ZERO;
+				internal_torque.torque = Vec3::ZERO;
+				continue;
+			};
+			internal_torque.torque = inertia.rotated(rotation).0 * angular;
+			internal_torque.frame = InternalFrame::World;
+		}
+	}
+}
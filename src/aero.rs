@@ -0,0 +1,235 @@
+//! Aerodynamic lift and drag from surfaces like wings, fins and tail planes.
+
+use serde::{Deserialize, Serialize};
+
+use crate::gimbal::gimballed_rotation;
+use crate::hierarchy::{world_lever_arm, world_point, RigidBodyAncestors};
+use crate::prelude::*;
+
+/// The density of the air used by every [AeroSurface], in kilograms per cubic meter.
+/// Defaults to `1.225`, the density at sea level
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct AirDensity(pub f32);
+
+impl Default for AirDensity {
+	fn default() -> Self {
+		Self(1.225)
+	}
+}
+
+/// The lift coefficient of an [AeroSurface] for each angle of attack (in radians).
+#[derive(Reflect, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LiftCurve {
+	/// Increases linearly with the angle of attack by `slope` per radian (`2 * PI` for a thin airfoil)
+	/// until `stall_angle`, after which it drops to that of a flat plate
+	Linear { slope: f32, stall_angle: f32 },
+	/// Linearly interpolated between `(angle_of_attack, lift_coefficient)` points sorted by angle,
+	/// and constant beyond the first and last points
+	Table(Vec<(f32, f32)>),
+}
+
+impl Default for LiftCurve {
+	fn default() -> Self {
+		Self::Linear {
+			slope: std::f32::consts::TAU,
+			stall_angle: 15f32.to_radians(),
+		}
+	}
+}
+
+impl LiftCurve {
+	pub fn lift_coefficient(&self, angle_of_attack: f32) -> f32 {
+		match self {
+			LiftCurve::Linear { slope, stall_angle } => {
+				if angle_of_attack.abs() <= *stall_angle {
+					slope * angle_of_attack
+				} else {
+					// a flat plate, which produces the most lift at 45 degrees
+					(2.0 * angle_of_attack).sin()
+				}
+			}
+			LiftCurve::Table(points) => {
+				let Some(first) = points.first() else {
+					return 0.0;
+				};
+				if angle_of_attack <= first.0 {
+					return first.1;
+				}
+				for window in points.windows(2) {
+					let (start, end) = (window[0], window[1]);
+					if angle_of_attack <= end.0 {
+						let t = (angle_of_attack - start.0) / (end.0 - start.0);
+						return start.1 + (end.1 - start.1) * t;
+					}
+				}
+				points.last().map_or(0.0, |last| last.1)
+			}
+		}
+	}
+}
+
+/// A wing, fin or any other surface producing lift and drag from the air flowing past it,
+/// by writing a [InternalFrame::World] [InternalForce] every time
+/// [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs.
+///
+/// The airflow is measured at the child's position, from its [RigidBody] ancestor's [LinearVelocity]
+/// and its [AngularVelocity] crossed with the lever arm, relative to the [WindField] at that position.
+/// The component of the airflow along the span (perpendicular to the chord and normal) is ignored.
+///
+/// The [InternalForce] is inserted automatically if missing.
+/// Adding a [Gimbal] to the same child deflects the surface, e.g. for ailerons and elevators.
+#[derive(Reflect, Component, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct AeroSurface {
+	/// The area of the surface, in square meters
+	pub area: f32,
+	/// The direction from the trailing edge to the leading edge, in the child's local space.
+	/// Defaults to [Vec3::NEG_Z] (bevy's forward)
+	pub chord: Vec3,
+	/// The direction lift is produced in at a positive angle of attack, in the child's local space.
+	/// Defaults to [Vec3::Y]
+	pub normal: Vec3,
+	pub lift_curve: LiftCurve,
+	/// The drag coefficient at zero lift
+	pub drag_coefficient: f32,
+	/// The extra drag coefficient per squared lift coefficient
+	pub induced_drag_factor: f32,
+}
+
+impl AeroSurface {
+	/// Creates a horizontal surface facing forward with the default [LiftCurve]
+	pub fn new(area: f32) -> Self {
+		Self {
+			area,
+			chord: Vec3::NEG_Z,
+			normal: Vec3::Y,
+			lift_curve: LiftCurve::default(),
+			drag_coefficient: 0.02,
+			induced_drag_factor: 0.05,
+		}
+	}
+
+	/// Orients the surface, in the child's local space
+	pub fn with_orientation(mut self, chord: Vec3, normal: Vec3) -> Self {
+		self.chord = chord;
+		self.normal = normal;
+		self
+	}
+
+	pub fn with_lift_curve(mut self, lift_curve: LiftCurve) -> Self {
+		self.lift_curve = lift_curve;
+		self
+	}
+
+	pub fn with_drag(mut self, drag_coefficient: f32, induced_drag_factor: f32) -> Self {
+		self.drag_coefficient = drag_coefficient;
+		self.induced_drag_factor = induced_drag_factor;
+		self
+	}
+
+	/// Computes the world space force on the surface, given its world space `rotation`
+	/// and the world space velocity of the `airflow` past it (i.e. the negative of its velocity in still air)
+	pub fn compute_force(&self, rotation: Quat, airflow: Vec3, air_density: f32) -> Vec3 {
+		let chord = rotation.mul_vec3(self.chord).normalize_or_zero();
+		let normal = rotation.mul_vec3(self.normal).normalize_or_zero();
+		let span = chord.cross(normal).normalize_or_zero();
+		if span == Vec3::ZERO {
+			return Vec3::ZERO;
+		}
+
+		let airflow = airflow - span * airflow.dot(span);
+		let speed_squared = airflow.length_squared();
+		if speed_squared <= f32::EPSILON {
+			return Vec3::ZERO;
+		}
+		let flow_direction = airflow / speed_squared.sqrt();
+
+		// the surface moves against the airflow
+		let angle_of_attack = flow_direction.dot(normal).atan2(-flow_direction.dot(chord));
+		let lift_coefficient = self.lift_curve.lift_coefficient(angle_of_attack);
+		let drag_coefficient =
+			self.drag_coefficient + self.induced_drag_factor * lift_coefficient * lift_coefficient;
+
+		let dynamic_pressure = 0.5 * air_density * speed_squared * self.area;
+		let lift_direction = flow_direction.cross(span);
+		(lift_direction * lift_coefficient + flow_direction * drag_coefficient) * dynamic_pressure
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts an [InternalForce] on every [AeroSurface] that doesn't have one yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_aero_surfaces(
+		mut commands: Commands,
+		surfaces: Query<Entity, (With<AeroSurface>, Without<InternalForce>)>,
+	) {
+		for entity in surfaces.iter() {
+			commands
+				.entity(entity)
+				.insert(InternalForce::new_global(Vec3::ZERO));
+		}
+	}
+
+	/// Writes the lift and drag of every [AeroSurface] into its [InternalForce].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn update_aero_surfaces(
+		mut surfaces: Query<
+			(Entity, &AeroSurface, Option<&Gimbal>, &mut InternalForce),
+			Without<RigidBody>,
+		>,
+		parents: Query<
+			(
+				&LinearVelocity,
+				&AngularVelocity,
+				&CenterOfMass,
+				&Position,
+				&Rotation,
+				&GlobalTransform,
+			),
+			With<RigidBody>,
+		>,
+		ancestors: RigidBodyAncestors,
+		air_density: Res<AirDensity>,
+		wind: Res<WindField>,
+		time: Res<Time<Physics>>,
+	) {
+		for (child, surface, gimbal, mut internal_force) in surfaces.iter_mut() {
+			let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+				continue;
+			};
+			let Ok((
+				linear_velocity,
+				angular_velocity,
+				center_of_mass,
+				parent_position,
+				parent_rotation,
+				parent_global_transform,
+			)) = parents.get(rigid_body)
+			else {
+				continue;
+			};
+
+			let scale = parent_global_transform.compute_transform().scale;
+			let lever_arm = world_lever_arm(
+				child_relative_transform.translation,
+				scale,
+				parent_rotation.0,
+				center_of_mass.0,
+			);
+			let velocity = linear_velocity.0 + angular_velocity.0.cross(lever_arm);
+			let position = world_point(
+				child_relative_transform.translation,
+				scale,
+				parent_position.0,
+				parent_rotation.0,
+			);
+			let airflow = wind.velocity_at(position, time.elapsed_seconds()) - velocity;
+			let rotation =
+				parent_rotation.0 * gimballed_rotation(child_relative_transform.rotation, gimbal);
+
+			let force = surface.compute_force(rotation, airflow, air_density.0);
+			*internal_force = InternalForce::new_global(force);
+		}
+	}
+}
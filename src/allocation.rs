@@ -0,0 +1,288 @@
+//! Allocating a desired wrench to the strengths of a parent's children.
+
+use bevy::ecs::entity::EntityHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::frame::EntityFrames;
+use crate::gimbal::gimballed_rotation;
+use crate::hierarchy::RigidBodyAncestors;
+use crate::prelude::*;
+
+/// How many passes [allocate] makes over the actuators at most
+const MAX_ITERATIONS: usize = 200;
+/// [allocate] stops early once no strength changes by more than this in a pass
+const TOLERANCE: f32 = 1e-6;
+/// Keeps the problem well posed when actuators are redundant,
+/// preferring the solution with the least total strength.
+/// Relative to the average squared magnitude of the actuators
+const REGULARIZATION: f32 = 1e-4;
+
+/// Commands the children of a [RigidBody] with [AllocationLimits] to together exert a desired
+/// force and torque on it, e.g. for an RCS or a drone's motors.
+///
+/// Every time [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs,
+/// the strength of each allocated [InternalForce] (or the throttle of each allocated [Thruster])
+/// is chosen to get as close as possible to the desired wrench, within each child's limits.
+/// Systems setting the desired wrench should be scheduled before it.
+#[derive(Reflect, Component, Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct ThrustAllocator {
+	/// The desired force, in the space of the parent body
+	pub force: Vec3,
+	/// The desired torque around the center of mass, in the space of the parent body
+	pub torque: Vec3,
+	/// The force requested by controllers like [AttitudeHold](crate::AttitudeHold),
+	/// added on top of [ThrustAllocator::force]
+	#[serde(skip)]
+	pub(crate) feedback_force: Vec3,
+	/// The torque requested by controllers like [AttitudeHold](crate::AttitudeHold),
+	/// added on top of [ThrustAllocator::torque]
+	#[serde(skip)]
+	pub(crate) feedback_torque: Vec3,
+	/// The force that the allocated strengths actually produce, in the space of the parent body
+	#[serde(skip)]
+	achieved_force: Vec3,
+	/// The torque that the allocated strengths actually produce, in the space of the parent body
+	#[serde(skip)]
+	achieved_torque: Vec3,
+}
+
+impl ThrustAllocator {
+	/// Creates a [ThrustAllocator] asking for a body space `force` and `torque`
+	pub fn new(force: Vec3, torque: Vec3) -> Self {
+		Self {
+			force,
+			torque,
+			..default()
+		}
+	}
+
+	/// The body space force that is allocated, including the force requested by controllers
+	pub fn get_commanded_force(&self) -> Vec3 {
+		self.force + self.feedback_force
+	}
+
+	/// The body space torque that is allocated, including the torque requested by controllers
+	pub fn get_commanded_torque(&self) -> Vec3 {
+		self.torque + self.feedback_torque
+	}
+
+	/// The body space force produced by the last allocation,
+	/// which differs from the commanded force when the children's limits make it unreachable
+	pub fn get_achieved_force(&self) -> Vec3 {
+		self.achieved_force
+	}
+
+	/// The body space torque produced by the last allocation,
+	/// which differs from the commanded torque when the children's limits make it unreachable
+	pub fn get_achieved_torque(&self) -> Vec3 {
+		self.achieved_torque
+	}
+}
+
+/// Marks a child whose strength is chosen by its parent's [ThrustAllocator], within these limits.
+///
+/// For an [InternalForce], the strength is [InternalForce::strength] and its [InternalForce::force]
+/// is the force at a strength of `1.0`.
+/// For a [Thruster], the strength is its throttle, which is also always kept between `0.0` and `1.0`.
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct AllocationLimits {
+	pub min: f32,
+	pub max: f32,
+}
+
+impl AllocationLimits {
+	pub fn new(min: f32, max: f32) -> Self {
+		Self { min, max }
+	}
+}
+
+/// A single actuator for [allocate]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Actuator {
+	/// The force exerted at a strength of `1.0`
+	pub force: Vec3,
+	/// The torque exerted at a strength of `1.0`
+	pub torque: Vec3,
+	pub min: f32,
+	pub max: f32,
+}
+
+/// Finds the strength of each actuator, within its limits, whose combined wrench is closest
+/// (in the least-squares sense) to the desired `force` and `torque`.
+///
+/// Forces and torques are weighted equally. When several solutions are equally close,
+/// the one with the least total strength is preferred.
+pub fn allocate(actuators: &[Actuator], force: Vec3, torque: Vec3) -> Vec<f32> {
+	let mut strengths: Vec<f32> = actuators
+		.iter()
+		.map(|actuator| clamp_strength(0.0, actuator))
+		.collect();
+	if actuators.is_empty() {
+		return strengths;
+	}
+
+	let mean_norm = actuators
+		.iter()
+		.map(|actuator| actuator.force.length_squared() + actuator.torque.length_squared())
+		.sum::<f32>()
+		/ actuators.len() as f32;
+	let regularization = REGULARIZATION * mean_norm;
+
+	// the part of the desired wrench not yet produced
+	let mut residual_force = force;
+	let mut residual_torque = torque;
+	for (actuator, strength) in actuators.iter().zip(strengths.iter()) {
+		residual_force -= actuator.force * *strength;
+		residual_torque -= actuator.torque * *strength;
+	}
+
+	// projected coordinate descent, which converges for this convex box constrained problem
+	for _ in 0..MAX_ITERATIONS {
+		let mut max_change: f32 = 0.0;
+		for (actuator, strength) in actuators.iter().zip(strengths.iter_mut()) {
+			let norm = actuator.force.length_squared() + actuator.torque.length_squared();
+			if norm + regularization <= 0.0 {
+				continue;
+			}
+			let gradient = actuator.force.dot(residual_force) + actuator.torque.dot(residual_torque)
+				- regularization * *strength;
+			let new_strength = clamp_strength(*strength + gradient / (norm + regularization), actuator);
+			let change = new_strength - *strength;
+			if change != 0.0 {
+				residual_force -= actuator.force * change;
+				residual_torque -= actuator.torque * change;
+				*strength = new_strength;
+				max_change = max_change.max(change.abs());
+			}
+		}
+		if max_change < TOLERANCE {
+			break;
+		}
+	}
+
+	strengths
+}
+
+fn clamp_strength(strength: f32, actuator: &Actuator) -> f32 {
+	if actuator.min <= actuator.max {
+		strength.clamp(actuator.min, actuator.max)
+	} else {
+		actuator.min
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Sets the strength (or throttle) of every child with [AllocationLimits],
+	/// so that together they produce their parent's [ThrustAllocator] wrench.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn allocate_thrust(
+		mut parents: Query<
+			(
+				&mut ThrustAllocator,
+				&CenterOfMass,
+				&Rotation,
+				&GlobalTransform,
+			),
+			With<RigidBody>,
+		>,
+		mut children: Query<
+			(
+				&mut InternalForce,
+				&AllocationLimits,
+				Option<&mut Thruster>,
+				Option<&Gimbal>,
+			),
+			Without<RigidBody>,
+		>,
+		allocated: Query<
+			Entity,
+			(
+				With<AllocationLimits>,
+				With<InternalForce>,
+				Without<RigidBody>,
+			),
+		>,
+		ancestors: RigidBodyAncestors,
+		entity_frames: EntityFrames,
+	) {
+		let mut groups: EntityHashMap<(Vec<Entity>, Vec<Actuator>)> = EntityHashMap::default();
+		for child in allocated.iter() {
+			let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+				continue;
+			};
+			let Ok((_, center_of_mass, parent_rotation, parent_global_transform)) =
+				parents.get(rigid_body)
+			else {
+				continue;
+			};
+			let Ok((internal_force, limits, thruster, gimbal)) = children.get(child) else {
+				continue;
+			};
+
+			// the force at a strength of 1.0, in its own frame
+			let (unit_force, frame, min, max) = match thruster {
+				Some(thruster) => (
+					thruster.compute_max_thrust(),
+					InternalFrame::ChildLocal,
+					limits.min.max(0.0),
+					limits.max.min(1.0),
+				),
+				None => (
+					internal_force.force,
+					internal_force.frame,
+					limits.min,
+					limits.max,
+				),
+			};
+			let Some(world_force) = frame.to_world(
+				unit_force,
+				parent_rotation.0,
+				gimballed_rotation(child_relative_transform.rotation, gimbal),
+				|entity| entity_frames.rotation(entity),
+			) else {
+				continue;
+			};
+			let body_force = parent_rotation.0.inverse().mul_vec3(world_force);
+			let scale = parent_global_transform.compute_transform().scale;
+			let body_lever_arm = child_relative_transform.translation * scale - center_of_mass.0;
+
+			let (entities, actuators) = groups.entry(rigid_body).or_default();
+			entities.push(child);
+			actuators.push(Actuator {
+				force: body_force,
+				torque: body_lever_arm.cross(body_force),
+				min,
+				max,
+			});
+		}
+
+		for (rigid_body, (entities, actuators)) in groups {
+			let Ok((mut allocator, ..)) = parents.get_mut(rigid_body) else {
+				continue;
+			};
+			let strengths = allocate(
+				&actuators,
+				allocator.get_commanded_force(),
+				allocator.get_commanded_torque(),
+			);
+
+			let mut achieved_force = Vec3::ZERO;
+			let mut achieved_torque = Vec3::ZERO;
+			for ((child, actuator), strength) in entities.into_iter().zip(actuators).zip(strengths) {
+				achieved_force += actuator.force * strength;
+				achieved_torque += actuator.torque * strength;
+				let Ok((mut internal_force, _, thruster, _)) = children.get_mut(child) else {
+					continue;
+				};
+				match thruster {
+					Some(mut thruster) => thruster.set_throttle(strength),
+					None => internal_force.set_strength(strength),
+				}
+			}
+			allocator.achieved_force = achieved_force;
+			allocator.achieved_torque = achieved_torque;
+		}
+	}
+}
@@ -0,0 +1,409 @@
+//! Buoyancy and water drag from floating volumes, like a boat's pontoons.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hierarchy::{world_lever_arm, world_point, RigidBodyAncestors};
+use crate::prelude::*;
+
+/// The height of the water's surface, see [Water]
+#[derive(Clone)]
+pub enum WaterSurface {
+	/// A flat surface at this world Y position
+	Flat(f32),
+	/// Returns the world Y position of the surface at a world position and time (in seconds of the physics clock),
+	/// e.g. for waves
+	Function(Arc<dyn Fn(Vec3, f32) -> f32 + Send + Sync>),
+}
+
+impl std::fmt::Debug for WaterSurface {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			WaterSurface::Flat(height) => f.debug_tuple("Flat").field(height).finish(),
+			WaterSurface::Function(_) => f.write_str("Function(..)"),
+		}
+	}
+}
+
+impl WaterSurface {
+	/// Creates a [WaterSurface::Function] from a closure
+	pub fn from_fn(height: impl Fn(Vec3, f32) -> f32 + Send + Sync + 'static) -> Self {
+		Self::Function(Arc::new(height))
+	}
+
+	/// The world Y position of the surface at a world `position` and time
+	pub fn height_at(&self, position: Vec3, elapsed_seconds: f32) -> f32 {
+		match self {
+			WaterSurface::Flat(height) => *height,
+			WaterSurface::Function(height) => height(position, elapsed_seconds),
+		}
+	}
+}
+
+/// The water every [BuoyancyVolume] floats in.
+/// Defaults to fresh water (`1000.0` kilograms per cubic meter) with a flat surface at a height of `0.0`
+#[derive(Resource, Debug, Clone)]
+pub struct Water {
+	/// In kilograms per cubic meter
+	pub density: f32,
+	pub surface: WaterSurface,
+}
+
+impl Default for Water {
+	fn default() -> Self {
+		Self {
+			density: 1000.0,
+			surface: WaterSurface::Flat(0.0),
+		}
+	}
+}
+
+/// The shape of a [BuoyancyVolume], in the child's local space (ignoring scale)
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BuoyancyShape {
+	Sphere {
+		radius: f32,
+	},
+	Cuboid {
+		half_extents: Vec3,
+	},
+	/// Along the child's local Y axis
+	Capsule {
+		radius: f32,
+		half_height: f32,
+	},
+}
+
+impl BuoyancyShape {
+	pub fn volume(&self) -> f32 {
+		use std::f32::consts::PI;
+		match *self {
+			BuoyancyShape::Sphere { radius } => 4.0 / 3.0 * PI * radius.powi(3),
+			BuoyancyShape::Cuboid { half_extents } => {
+				8.0 * half_extents.x * half_extents.y * half_extents.z
+			}
+			BuoyancyShape::Capsule {
+				radius,
+				half_height,
+			} => 4.0 / 3.0 * PI * radius.powi(3) + PI * radius * radius * 2.0 * half_height,
+		}
+	}
+
+	/// The submerged fraction of the shape and its world space center of buoyancy, given the shape's world space
+	/// `center` and `rotation` and the height of the water's `surface`. Returns `None` when it isn't submerged at all.
+	///
+	/// Spheres and cuboids are clipped by the surface exactly,
+	/// while capsules are sliced along their axis into thin slices.
+	pub fn submerged(&self, center: Vec3, rotation: Quat, surface: f32) -> Option<(f32, Vec3)> {
+		let (fraction, center_of_buoyancy) = match *self {
+			BuoyancyShape::Sphere { radius } => {
+				let depth = (surface - (center.y - radius)).min(2.0 * radius);
+				if depth <= 0.0 || radius <= 0.0 {
+					return None;
+				}
+				// an exact spherical cap, directly below the center
+				let cap_volume = std::f32::consts::PI * depth * depth * (3.0 * radius - depth) / 3.0;
+				// the distance from the bottom of the sphere to the cap's centroid
+				let centroid = depth * (8.0 * radius - 3.0 * depth) / (4.0 * (3.0 * radius - depth));
+				(
+					cap_volume / self.volume(),
+					Vec3::new(center.x, center.y - radius + centroid, center.z),
+				)
+			}
+			BuoyancyShape::Cuboid { half_extents } => {
+				let (volume, centroid) = submerged_cuboid(half_extents, center, rotation, surface)?;
+				(volume / self.volume(), centroid)
+			}
+			BuoyancyShape::Capsule {
+				radius,
+				half_height,
+			} => submerged_capsule(radius, half_height, center, rotation, surface)?,
+		};
+		(fraction > 0.0).then_some((fraction.min(1.0), center_of_buoyancy))
+	}
+}
+
+/// How many slices a capsule is cut into along its axis when computing how much of it is submerged:
+/// half through its cylinder, and a quarter through each hemisphere
+const CAPSULE_SLICES: usize = 32;
+
+/// The submerged volume and its centroid of a cuboid, by clipping each face to below the `surface`.
+///
+/// By the divergence theorem, the volume is the sum of the tetrahedra between each (clipped) face
+/// and a reference point. Choosing a point on the surface means that the face the surface cuts
+/// through the cuboid contributes nothing, so doesn't need to be constructed.
+fn submerged_cuboid(
+	half_extents: Vec3,
+	center: Vec3,
+	rotation: Quat,
+	surface: f32,
+) -> Option<(f32, Vec3)> {
+	let reference = Vec3::new(center.x, surface, center.z);
+	let mut volume = 0.0;
+	let mut moment = Vec3::ZERO;
+	for axis in 0..3 {
+		for sign in [-1.0, 1.0] {
+			let normal = Vec3::AXES[axis] * sign;
+			// ordered so that the corners are counter-clockwise when viewed from outside
+			let (u, v) = if sign > 0.0 {
+				(Vec3::AXES[(axis + 1) % 3], Vec3::AXES[(axis + 2) % 3])
+			} else {
+				(Vec3::AXES[(axis + 2) % 3], Vec3::AXES[(axis + 1) % 3])
+			};
+			let face = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+				.map(|(a, b)| center + rotation.mul_vec3((normal + u * a + v * b) * half_extents));
+
+			let clipped = clip_below(&face, surface);
+			for i in 1..clipped.len().saturating_sub(1) {
+				let (a, b, c) = (clipped[0], clipped[i], clipped[i + 1]);
+				let tetrahedron = (a - reference).dot((b - reference).cross(c - reference)) / 6.0;
+				volume += tetrahedron;
+				moment += tetrahedron * (reference + a + b + c) / 4.0;
+			}
+		}
+	}
+	(volume > 0.0).then(|| (volume, moment / volume))
+}
+
+/// Clips a convex polygon to the part below the world Y position `surface`
+fn clip_below(polygon: &[Vec3], surface: f32) -> Vec<Vec3> {
+	let mut clipped = Vec::with_capacity(polygon.len() + 1);
+	for (i, &a) in polygon.iter().enumerate() {
+		let b = polygon[(i + 1) % polygon.len()];
+		let (a_below, b_below) = (a.y <= surface, b.y <= surface);
+		if a_below {
+			clipped.push(a);
+		}
+		if a_below != b_below {
+			clipped.push(a.lerp(b, (surface - a.y) / (b.y - a.y)));
+		}
+	}
+	clipped
+}
+
+/// The submerged fraction and its centroid of a capsule, by cutting it into [CAPSULE_SLICES] discs
+/// perpendicular to its axis, each of which the surface cuts into an exact circular segment
+fn submerged_capsule(
+	radius: f32,
+	half_height: f32,
+	center: Vec3,
+	rotation: Quat,
+	surface: f32,
+) -> Option<(f32, Vec3)> {
+	if radius <= 0.0 {
+		return None;
+	}
+	let axis = rotation.mul_vec3(Vec3::Y);
+	// how far each disc reaches upwards per unit of its radius
+	let upwards = Vec3::Y - axis * axis.y;
+	let tilt = upwards.length();
+
+	let cylinder_slices = CAPSULE_SLICES / 2;
+	let cap_slices = CAPSULE_SLICES / 4;
+	let slices = (0..cylinder_slices)
+		.map(|i| {
+			let thickness = 2.0 * half_height / cylinder_slices as f32;
+			let offset = -half_height + thickness * (i as f32 + 0.5);
+			(offset, thickness, radius)
+		})
+		.chain((0..cap_slices).flat_map(|i| {
+			let thickness = radius / cap_slices as f32;
+			let distance = thickness * (i as f32 + 0.5);
+			let slice_radius = (radius * radius - distance * distance).sqrt();
+			[
+				(half_height + distance, thickness, slice_radius),
+				(-half_height - distance, thickness, slice_radius),
+			]
+		}));
+
+	let mut total_volume = 0.0;
+	let mut volume = 0.0;
+	let mut moment = Vec3::ZERO;
+	for (offset, thickness, slice_radius) in slices {
+		let slice_center = center + axis * offset;
+		let full_area = std::f32::consts::PI * slice_radius * slice_radius;
+		total_volume += full_area * thickness;
+
+		let (area, centroid) = if tilt < 1e-4 {
+			// the disc is horizontal
+			if slice_center.y < surface {
+				(full_area, slice_center)
+			} else {
+				continue;
+			}
+		} else {
+			// the disc is below the surface on one side of a chord, this far from its center
+			let chord = (surface - slice_center.y) / tilt;
+			if chord >= slice_radius {
+				(full_area, slice_center)
+			} else if chord <= -slice_radius {
+				continue;
+			} else {
+				let half_chord_squared = slice_radius * slice_radius - chord * chord;
+				let area = slice_radius * slice_radius * (-chord / slice_radius).acos()
+					+ chord * half_chord_squared.sqrt();
+				let distance = 2.0 * half_chord_squared.powf(1.5) / (3.0 * area);
+				(area, slice_center - upwards / tilt * distance)
+			}
+		};
+		volume += area * thickness;
+		moment += area * thickness * centroid;
+	}
+	(volume > 0.0).then(|| (volume / total_volume, moment / volume))
+}
+
+/// A volume that floats in the [Water], pushing its [RigidBody] ancestor up at its center of buoyancy,
+/// and slowing it down with water drag in proportion to how much of it is submerged.
+///
+/// Writes [InternalFrame::World] [InternalForce] and [InternalTorque]s (inserted automatically if missing)
+/// every time [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs.
+/// A boat can be built from several volumes, e.g. one per pontoon.
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct BuoyancyVolume {
+	pub shape: BuoyancyShape,
+	/// The drag force per unit of velocity (at the volume's position) when fully submerged
+	pub linear_drag: f32,
+	/// The drag torque per unit of the parent's angular velocity when fully submerged
+	pub angular_drag: f32,
+}
+
+impl BuoyancyVolume {
+	pub fn new(shape: BuoyancyShape) -> Self {
+		Self {
+			shape,
+			linear_drag: 0.0,
+			angular_drag: 0.0,
+		}
+	}
+
+	pub fn sphere(radius: f32) -> Self {
+		Self::new(BuoyancyShape::Sphere { radius })
+	}
+
+	pub fn cuboid(half_extents: Vec3) -> Self {
+		Self::new(BuoyancyShape::Cuboid { half_extents })
+	}
+
+	pub fn capsule(radius: f32, half_height: f32) -> Self {
+		Self::new(BuoyancyShape::Capsule {
+			radius,
+			half_height,
+		})
+	}
+
+	pub fn with_drag(mut self, linear_drag: f32, angular_drag: f32) -> Self {
+		self.linear_drag = linear_drag;
+		self.angular_drag = angular_drag;
+		self
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts an [InternalForce] and [InternalTorque] on every [BuoyancyVolume] that doesn't have them yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_buoyancy_volumes(
+		mut commands: Commands,
+		volumes: Query<
+			(Entity, Has<InternalForce>, Has<InternalTorque>),
+			(
+				With<BuoyancyVolume>,
+				Or<(Without<InternalForce>, Without<InternalTorque>)>,
+			),
+		>,
+	) {
+		for (entity, has_force, has_torque) in volumes.iter() {
+			let mut entity_commands = commands.entity(entity);
+			if !has_force {
+				entity_commands.insert(InternalForce::new_global(Vec3::ZERO));
+			}
+			if !has_torque {
+				entity_commands.insert(InternalTorque::new_global(Vec3::ZERO));
+			}
+		}
+	}
+
+	/// Writes the buoyancy and water drag of every [BuoyancyVolume] into its [InternalForce] and [InternalTorque].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn update_buoyancy_volumes(
+		mut volumes: Query<
+			(
+				Entity,
+				&BuoyancyVolume,
+				&mut InternalForce,
+				&mut InternalTorque,
+			),
+			Without<RigidBody>,
+		>,
+		parents: Query<
+			(
+				&Position,
+				&Rotation,
+				&LinearVelocity,
+				&AngularVelocity,
+				&CenterOfMass,
+				&GlobalTransform,
+			),
+			With<RigidBody>,
+		>,
+		ancestors: RigidBodyAncestors,
+		water: Res<Water>,
+		gravity: Option<Res<Gravity>>,
+		time: Res<Time<Physics>>,
+	) {
+		let gravity = gravity.map_or(Vec3::ZERO, |gravity| gravity.0);
+		for (child, volume, mut internal_force, mut internal_torque) in volumes.iter_mut() {
+			let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+				continue;
+			};
+			let Ok((
+				position,
+				rotation,
+				linear_velocity,
+				angular_velocity,
+				center_of_mass,
+				parent_global_transform,
+			)) = parents.get(rigid_body)
+			else {
+				continue;
+			};
+
+			let scale = parent_global_transform.compute_transform().scale;
+			let center = world_point(
+				child_relative_transform.translation,
+				scale,
+				position.0,
+				rotation.0,
+			);
+			let volume_rotation = rotation.0 * child_relative_transform.rotation;
+			let surface = water.surface.height_at(center, time.elapsed_seconds());
+
+			let mut force = Vec3::ZERO;
+			let mut torque = Vec3::ZERO;
+			if let Some((fraction, center_of_buoyancy)) =
+				volume.shape.submerged(center, volume_rotation, surface)
+			{
+				// Archimedes' principle
+				let buoyancy = -gravity * water.density * volume.shape.volume() * fraction;
+				force += buoyancy;
+				// the force is applied at the child's position, not the center of buoyancy,
+				// which shifts towards the deeper side of a tilted volume and rights it
+				torque += (center_of_buoyancy - center).cross(buoyancy);
+
+				let lever_arm = world_lever_arm(
+					child_relative_transform.translation,
+					scale,
+					rotation.0,
+					center_of_mass.0,
+				);
+				let velocity = linear_velocity.0 + angular_velocity.0.cross(lever_arm);
+				force -= velocity * volume.linear_drag * fraction;
+				torque -= angular_velocity.0 * volume.angular_drag * fraction;
+			}
+
+			*internal_force = InternalForce::new_global(force);
+			*internal_torque = InternalTorque::new_global(torque);
+		}
+	}
+}
@@ -0,0 +1,313 @@
+//! PID controllers holding a [RigidBody]'s attitude, heading, altitude or velocity
+//! using its children, through its [ThrustAllocator].
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::setup::ParentComponent;
+
+/// A proportional-integral-derivative controller over [Vec3] errors,
+/// with the integral clamped to prevent windup.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d_parenting::Pid;
+///
+/// let mut pid = Pid::new(2.0, 1.0, 0.0).with_max_integral(0.5);
+/// // a constant error would wind up the integral forever, without the limit
+/// for _ in 0..100 {
+///   pid.update(Vec3::X, None, 0.1);
+/// }
+/// assert_eq!(pid.get_integral(), Vec3::X * 0.5);
+/// ```
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Pid {
+	/// The proportional gain
+	pub p: f32,
+	/// The integral gain
+	pub i: f32,
+	/// The derivative gain
+	pub d: f32,
+	/// The maximum magnitude of each component of the accumulated error.
+	/// Defaults to [f32::INFINITY], i.e. no anti-windup
+	pub max_integral: f32,
+	#[serde(skip)]
+	integral: Vec3,
+	#[serde(skip)]
+	previous_error: Option<Vec3>,
+}
+
+impl Default for Pid {
+	fn default() -> Self {
+		Self::new(1.0, 0.0, 0.0)
+	}
+}
+
+impl Pid {
+	pub fn new(p: f32, i: f32, d: f32) -> Self {
+		Self {
+			p,
+			i,
+			d,
+			max_integral: f32::INFINITY,
+			integral: Vec3::ZERO,
+			previous_error: None,
+		}
+	}
+
+	pub fn with_max_integral(mut self, max_integral: f32) -> Self {
+		self.max_integral = max_integral.abs();
+		self
+	}
+
+	/// The accumulated (and clamped) error
+	pub fn get_integral(&self) -> Vec3 {
+		self.integral
+	}
+
+	/// Forgets the accumulated error and the previous error
+	pub fn reset(&mut self) {
+		self.integral = Vec3::ZERO;
+		self.previous_error = None;
+	}
+
+	/// Computes the output for the current `error`, after `delta_seconds`.
+	///
+	/// `derivative` is the rate of change of the error, e.g. the negative of a measured velocity.
+	/// If it is `None`, it is estimated from the previous error instead.
+	pub fn update(&mut self, error: Vec3, derivative: Option<Vec3>, delta_seconds: f32) -> Vec3 {
+		let derivative = derivative.unwrap_or_else(|| match self.previous_error {
+			Some(previous_error) if delta_seconds > 0.0 => (error - previous_error) / delta_seconds,
+			_ => Vec3::ZERO,
+		});
+		self.previous_error = Some(error);
+		self.integral = (self.integral + error * delta_seconds).clamp(
+			Vec3::splat(-self.max_integral),
+			Vec3::splat(self.max_integral),
+		);
+
+		error * self.p + self.integral * self.i + derivative * self.d
+	}
+}
+
+/// Rotates a [RigidBody] towards a target [Rotation], by requesting torque from its [ThrustAllocator].
+/// The derivative term damps the [AngularVelocity].
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct AttitudeHold {
+	/// The world space rotation to hold
+	pub target: Quat,
+	/// Maps the rotation error (as a scaled axis, in radians) to a torque
+	pub pid: Pid,
+}
+
+impl AttitudeHold {
+	pub fn new(target: Quat, pid: Pid) -> Self {
+		Self { target, pid }
+	}
+}
+
+/// Turns a [RigidBody] around the world Y axis towards a target heading,
+/// by requesting torque from its [ThrustAllocator]. The derivative term damps the [AngularVelocity].
+///
+/// A heading of `0.0` faces [Vec3::NEG_Z] (bevy's forward), and increases counter-clockwise
+/// when looking down from above.
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct HeadingHold {
+	/// The heading to hold, in radians
+	pub target: f32,
+	/// Maps the heading error (in radians) to a torque
+	pub pid: Pid,
+}
+
+impl HeadingHold {
+	pub fn new(target: f32, pid: Pid) -> Self {
+		Self { target, pid }
+	}
+
+	/// The heading of a `rotation`, in radians between `-PI` and `PI`
+	pub fn heading_of(rotation: Quat) -> f32 {
+		let forward = rotation.mul_vec3(Vec3::NEG_Z);
+		(-forward.x).atan2(-forward.z)
+	}
+}
+
+/// Moves a [RigidBody] towards a target height (world Y [Position]),
+/// by requesting force from its [ThrustAllocator]. The derivative term damps the [LinearVelocity].
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct AltitudeHold {
+	/// The world Y position to hold
+	pub target: f32,
+	/// Maps the height error to a force
+	pub pid: Pid,
+	/// Whether to also request the force cancelling out [Gravity], using the parent's [Mass].
+	/// This removes the need for a large integral gain
+	pub compensate_gravity: bool,
+}
+
+impl AltitudeHold {
+	/// Creates an [AltitudeHold] that compensates for gravity
+	pub fn new(target: f32, pid: Pid) -> Self {
+		Self {
+			target,
+			pid,
+			compensate_gravity: true,
+		}
+	}
+}
+
+/// Accelerates a [RigidBody] towards a target world space [LinearVelocity],
+/// by requesting force from its [ThrustAllocator].
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct VelocityHold {
+	/// The world space velocity to hold
+	pub target: Vec3,
+	/// Maps the velocity error to a force
+	pub pid: Pid,
+}
+
+impl VelocityHold {
+	pub fn new(target: Vec3, pid: Pid) -> Self {
+		Self { target, pid }
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts a [ThrustAllocator] on every [RigidBody] with a controller that doesn't have one yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_controllers(
+		mut commands: Commands,
+		parents: Query<
+			Entity,
+			(
+				With<RigidBody>,
+				Without<ThrustAllocator>,
+				Or<(
+					With<AttitudeHold>,
+					With<HeadingHold>,
+					With<AltitudeHold>,
+					With<VelocityHold>,
+				)>,
+			),
+		>,
+		mut inserted_events: EventWriter<ParentComponentsInserted>,
+	) {
+		for parent in parents.iter() {
+			commands.entity(parent).insert(ThrustAllocator::default());
+			info!(
+				"Inserted a ThrustAllocator on parent {:?}, which its controllers need",
+				parent
+			);
+			inserted_events.send(ParentComponentsInserted {
+				parent,
+				components: vec![ParentComponent::ThrustAllocator],
+			});
+		}
+	}
+
+	/// Runs every controller over a single physics step, requesting the resulting force and torque
+	/// from the parent's [ThrustAllocator], which is allocated before the next physics step.
+	/// This is automatically scheduled in the [PhysicsSchedule](bevy_xpbd_3d::PhysicsSchedule)
+	pub(crate) fn run_controllers(
+		mut parents: Query<
+			(
+				&mut ThrustAllocator,
+				&Position,
+				&Rotation,
+				&LinearVelocity,
+				&AngularVelocity,
+				Option<&Mass>,
+				Option<&GravityScale>,
+				Option<&mut AttitudeHold>,
+				Option<&mut HeadingHold>,
+				Option<&mut AltitudeHold>,
+				Option<&mut VelocityHold>,
+			),
+			With<RigidBody>,
+		>,
+		gravity: Option<Res<Gravity>>,
+		time: Res<Time>,
+	) {
+		let delta_seconds = time.delta_seconds();
+		let gravity = gravity.map_or(Vec3::ZERO, |gravity| gravity.0);
+		for (
+			mut allocator,
+			position,
+			rotation,
+			linear_velocity,
+			angular_velocity,
+			mass,
+			gravity_scale,
+			attitude_hold,
+			heading_hold,
+			altitude_hold,
+			velocity_hold,
+		) in parents.iter_mut()
+		{
+			// in world space
+			let mut force = Vec3::ZERO;
+			let mut torque = Vec3::ZERO;
+
+			if let Some(mut attitude_hold) = attitude_hold {
+				let mut error = attitude_hold.target * rotation.0.inverse();
+				// take the shortest way around
+				if error.w < 0.0 {
+					error = -error;
+				}
+				torque += attitude_hold.pid.update(
+					error.to_scaled_axis(),
+					Some(-angular_velocity.0),
+					delta_seconds,
+				);
+			}
+
+			if let Some(mut heading_hold) = heading_hold {
+				let heading = HeadingHold::heading_of(rotation.0);
+				let error = wrap_angle(heading_hold.target - heading);
+				torque += heading_hold.pid.update(
+					Vec3::Y * error,
+					Some(Vec3::Y * -angular_velocity.y),
+					delta_seconds,
+				);
+			}
+
+			if let Some(mut altitude_hold) = altitude_hold {
+				let error = altitude_hold.target - position.y;
+				force += altitude_hold.pid.update(
+					Vec3::Y * error,
+					Some(Vec3::Y * -linear_velocity.y),
+					delta_seconds,
+				);
+				if altitude_hold.compensate_gravity {
+					let mass = mass.map_or(0.0, |mass| mass.0);
+					let scale = gravity_scale.map_or(1.0, |scale| scale.0);
+					force.y -= gravity.y * mass * scale;
+				}
+			}
+
+			if let Some(mut velocity_hold) = velocity_hold {
+				let error = velocity_hold.target - linear_velocity.0;
+				force += velocity_hold.pid.update(error, None, delta_seconds);
+			}
+
+			// the allocator works in the space of the parent body
+			let inverse_rotation = rotation.0.inverse();
+			let feedback_force = inverse_rotation.mul_vec3(force);
+			let feedback_torque = inverse_rotation.mul_vec3(torque);
+			if allocator.feedback_force != feedback_force || allocator.feedback_torque != feedback_torque
+			{
+				allocator.feedback_force = feedback_force;
+				allocator.feedback_torque = feedback_torque;
+			}
+		}
+	}
+}
+
+/// Wraps an angle to between `-PI` and `PI`
+fn wrap_angle(angle: f32) -> f32 {
+	use std::f32::consts::{PI, TAU};
+	(angle + PI).rem_euclid(TAU) - PI
+}
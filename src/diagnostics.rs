@@ -0,0 +1,207 @@
+//! Detecting misconfigured children and parents, without flooding the logs.
+
+use bevy::ecs::world::EntityRef;
+use bevy::utils::HashSet;
+
+use crate::prelude::*;
+
+/// A problem that stops a child from exerting forces on its parent.
+/// See [validate] and [ParentingDiagnostics].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParentingIssue {
+	/// A child with an [InternalForce], [InternalTorque] or [InternalImpulse]
+	/// has no [RigidBody] ancestor to exert them on.
+	NoRigidBodyAncestor { child: Entity },
+	/// A child with an [InternalForce], [InternalTorque] or [InternalImpulse]
+	/// is a [RigidBody] itself, so is simulated separately and its internal forces are ignored.
+	ChildIsRigidBody { child: Entity },
+	/// The [RigidBody] ancestor of a child with an [InternalForce] or [InternalImpulse]
+	/// has no [CenterOfMass], so the lever arm can't be computed.
+	MissingCenterOfMass { child: Entity, parent: Entity },
+	/// The [RigidBody] ancestor of a child with an [InternalImpulse] has a persistent [ExternalImpulse],
+	/// so the impulse would be applied every physics frame.
+	PersistentExternalImpulse { child: Entity, parent: Entity },
+	/// A child uses an [InternalFrame::Entity] that doesn't exist,
+	/// or has neither a [Rotation] nor a [GlobalTransform].
+	MissingFrameEntity { child: Entity, frame: Entity },
+	/// A [Thruster](crate::Thruster)'s [PropellantFeed](crate::PropellantFeed) points to an entity
+	/// without a [PropellantTank](crate::PropellantTank), so the thruster never pushes.
+	MissingPropellantTank { thruster: Entity, tank: Entity },
+	/// A `RaycastWheel`'s radius isn't positive, so it can't turn its torques into forces
+	/// and exerts nothing on its parent.
+	InvalidWheelRadius { wheel: Entity },
+}
+
+/// Sent once for every new [ParentingIssue], which is also logged as a warning.
+/// The same issue is only reported again if it is fixed and then reoccurs.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParentingDiagnostic {
+	pub issue: ParentingIssue,
+}
+
+/// Every [ParentingIssue] found the last time the [ParentingPlugin](crate::ParentingPlugin) checked,
+/// which is every frame in [ParentingSystemSet::Diagnose](crate::ParentingSystemSet::Diagnose).
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ParentingDiagnostics {
+	issues: HashSet<ParentingIssue>,
+}
+
+impl ParentingDiagnostics {
+	/// Every current issue, in no particular order
+	pub fn issues(&self) -> impl Iterator<Item = &ParentingIssue> {
+		self.issues.iter()
+	}
+
+	/// Returns `true` if everything is configured properly
+	pub fn is_empty(&self) -> bool {
+		self.issues.is_empty()
+	}
+
+	pub fn contains(&self, issue: &ParentingIssue) -> bool {
+		self.issues.contains(issue)
+	}
+}
+
+/// Checks every child with an [InternalForce], [InternalTorque], [InternalImpulse] or [GeneratedWrench] in the `world`,
+/// returning each distinct [ParentingIssue] once.
+///
+/// This is useful for asserting on in tests, the [ParentingPlugin](crate::ParentingPlugin)
+/// does the same check every frame to keep [ParentingDiagnostics] up to date.
+pub fn validate(world: &World) -> Vec<ParentingIssue> {
+	let mut issues = Vec::new();
+	for child in world.iter_entities().filter(is_internal_child) {
+		for issue in child_issues(world, child) {
+			if !issues.contains(&issue) {
+				issues.push(issue);
+			}
+		}
+	}
+	issues
+}
+
+fn is_internal_child(child: &EntityRef) -> bool {
+	child.contains::<InternalForce>()
+		|| child.contains::<InternalTorque>()
+		|| child.contains::<InternalImpulse>()
+		|| child.contains::<GeneratedWrench>()
+}
+
+/// Every [ParentingIssue] with a single child, which may contain duplicates
+fn child_issues(world: &World, child: EntityRef) -> Vec<ParentingIssue> {
+	let child_id = child.id();
+	let mut issues = Vec::new();
+
+	if child.contains::<RigidBody>() {
+		issues.push(ParentingIssue::ChildIsRigidBody { child: child_id });
+		return issues;
+	}
+
+	let frames = [
+		child.get::<InternalForce>().map(|force| force.frame),
+		child.get::<InternalTorque>().map(|torque| torque.frame),
+		child.get::<InternalImpulse>().map(|impulse| impulse.frame),
+		child
+			.get::<GeneratedWrench>()
+			.map(|generated| generated.frame),
+	];
+	for frame in frames.into_iter().flatten() {
+		if let InternalFrame::Entity(frame) = frame {
+			let has_frame = world
+				.get_entity(frame)
+				.is_some_and(|frame| frame.contains::<Rotation>() || frame.contains::<GlobalTransform>());
+			if !has_frame {
+				issues.push(ParentingIssue::MissingFrameEntity {
+					child: child_id,
+					frame,
+				});
+			}
+		}
+	}
+
+	if let Some(feed) = child.get::<PropellantFeed>() {
+		let has_tank = world
+			.get_entity(feed.tank)
+			.is_some_and(|tank| tank.contains::<PropellantTank>());
+		if !has_tank {
+			issues.push(ParentingIssue::MissingPropellantTank {
+				thruster: child_id,
+				tank: feed.tank,
+			});
+		}
+	}
+
+	#[cfg(feature = "wheels")]
+	if let Some(wheel) = child.get::<crate::RaycastWheel>() {
+		if !wheel.has_valid_radius() {
+			issues.push(ParentingIssue::InvalidWheelRadius { wheel: child_id });
+		}
+	}
+
+	let Some(parent) = rigid_body_ancestor(world, child) else {
+		issues.push(ParentingIssue::NoRigidBodyAncestor { child: child_id });
+		return issues;
+	};
+	let needs_lever_arm = child.contains::<InternalForce>()
+		|| child.contains::<InternalImpulse>()
+		|| child.contains::<GeneratedWrench>();
+	if needs_lever_arm && !parent.contains::<CenterOfMass>() {
+		issues.push(ParentingIssue::MissingCenterOfMass {
+			child: child_id,
+			parent: parent.id(),
+		});
+	}
+	let has_persistent_impulse = parent
+		.get::<ExternalImpulse>()
+		.is_some_and(|impulse| impulse.persistent);
+	if child.contains::<InternalImpulse>() && has_persistent_impulse {
+		issues.push(ParentingIssue::PersistentExternalImpulse {
+			child: child_id,
+			parent: parent.id(),
+		});
+	}
+
+	issues
+}
+
+fn rigid_body_ancestor<'w>(world: &'w World, child: EntityRef<'w>) -> Option<EntityRef<'w>> {
+	let mut parent = child.get::<Parent>();
+	loop {
+		let ancestor = world.get_entity(parent?.get())?;
+		if ancestor.contains::<RigidBody>() {
+			return Some(ancestor);
+		}
+		parent = ancestor.get::<Parent>();
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Updates the [ParentingDiagnostics], and sends (and logs) a [ParentingDiagnostic]
+	/// for every new [ParentingIssue].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn diagnose(
+		world: &mut World,
+		children: &mut QueryState<
+			Entity,
+			Or<(
+				With<InternalForce>,
+				With<InternalTorque>,
+				With<InternalImpulse>,
+				With<GeneratedWrench>,
+			)>,
+		>,
+	) {
+		let mut current = HashSet::new();
+		for child in children.iter(world) {
+			current.extend(child_issues(world, world.entity(child)));
+		}
+
+		let previous = std::mem::replace(
+			&mut world.resource_mut::<ParentingDiagnostics>().issues,
+			current.clone(),
+		);
+		for issue in current.difference(&previous) {
+			warn!("Internal forces can't be applied: {:?}", issue);
+			world.send_event(ParentingDiagnostic { issue: *issue });
+		}
+	}
+}
@@ -0,0 +1,174 @@
+//! Actuator dynamics, which make a child's applied strength lag behind its commanded strength.
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Makes a child respond to changes in its commanded strength over time, like a real engine, motor or hydraulic pusher.
+///
+/// The commanded strength is the [InternalForce::strength] of the child (or its [InternalTorque::strength],
+/// if it has no [InternalForce]), which is left untouched. The applied strength follows it through
+/// an ignition delay, a minimum on-level, a first order lag and rise and fall rate limits,
+/// and replaces the strength of both the [InternalForce] and [InternalTorque] when they are applied to the parent.
+/// For a [Thruster], the commanded strength is its throttle.
+///
+/// The applied strength is integrated once per physics step, starting at `0.0`.
+///
+/// ```rust
+/// use bevy_xpbd_3d_parenting::prelude::*;
+///
+/// // a jet engine, which takes a second to light and idles at 20% thrust
+/// let dynamics = ActuatorDynamics::new(0.8)
+///   .with_rates(0.5, 1.0)
+///   .with_min_level(0.2)
+///   .with_ignition_delay(1.0);
+/// assert_eq!(dynamics.get_applied(), 0.0);
+/// ```
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct ActuatorDynamics {
+	/// The time constant of the applied strength's first order response, in seconds.
+	/// `0.0` responds instantly (within the rate limits)
+	pub time_constant: f32,
+	/// The maximum rate that the magnitude of the applied strength increases at, per second
+	pub max_rise_rate: f32,
+	/// The maximum rate that the magnitude of the applied strength decreases at, per second
+	pub max_fall_rate: f32,
+	/// The lowest magnitude the actuator runs at while commanded on, e.g. an engine's idle.
+	/// Smaller non-zero commands are raised to this
+	pub min_level: f32,
+	/// How long the actuator must be commanded on before it starts responding, in seconds.
+	/// Commanding it off (a strength of `0.0`) shuts it down, and it must be ignited again
+	pub ignition_delay: f32,
+	applied: f32,
+	ignited: bool,
+	ignition_timer: f32,
+}
+
+impl ActuatorDynamics {
+	/// Creates [ActuatorDynamics] with a first order lag, without rate limits, a minimum on-level or an ignition delay
+	pub fn new(time_constant: f32) -> Self {
+		Self {
+			time_constant: time_constant.max(0.0),
+			max_rise_rate: f32::INFINITY,
+			max_fall_rate: f32::INFINITY,
+			min_level: 0.0,
+			ignition_delay: 0.0,
+			applied: 0.0,
+			ignited: false,
+			ignition_timer: 0.0,
+		}
+	}
+
+	pub fn with_rates(mut self, max_rise_rate: f32, max_fall_rate: f32) -> Self {
+		self.max_rise_rate = max_rise_rate.abs();
+		self.max_fall_rate = max_fall_rate.abs();
+		self
+	}
+
+	pub fn with_min_level(mut self, min_level: f32) -> Self {
+		self.min_level = min_level.abs();
+		self
+	}
+
+	pub fn with_ignition_delay(mut self, ignition_delay: f32) -> Self {
+		self.ignition_delay = ignition_delay.max(0.0);
+		self
+	}
+
+	/// The strength that is currently applied to the parent
+	pub fn get_applied(&self) -> f32 {
+		self.applied
+	}
+
+	/// Whether the actuator has finished its ignition delay, and is responding to its command
+	pub fn is_ignited(&self) -> bool {
+		self.ignited
+	}
+
+	/// Immediately applies `command`, skipping the ignition delay and any lag, e.g. when spawning an already running engine
+	pub fn snap_to(&mut self, command: f32) {
+		self.ignited = command != 0.0;
+		self.ignition_timer = 0.0;
+		self.applied = self.target(command);
+	}
+
+	/// The strength that the actuator is heading towards, once ignited
+	fn target(&self, command: f32) -> f32 {
+		if command == 0.0 || command.is_nan() {
+			0.0
+		} else {
+			command.signum() * command.abs().max(self.min_level)
+		}
+	}
+
+	/// Moves the applied strength towards `command` over `delta_seconds`
+	pub fn step(&mut self, command: f32, delta_seconds: f32) {
+		if command == 0.0 || command.is_nan() {
+			self.ignited = false;
+			self.ignition_timer = 0.0;
+		} else if !self.ignited {
+			self.ignition_timer += delta_seconds;
+			if self.ignition_timer >= self.ignition_delay {
+				self.ignited = true;
+				self.ignition_timer = 0.0;
+			}
+		}
+		let target = if self.ignited {
+			self.target(command)
+		} else {
+			0.0
+		};
+
+		let blend = if self.time_constant > 0.0 {
+			1.0 - (-delta_seconds / self.time_constant).exp()
+		} else {
+			1.0
+		};
+		let change = (target - self.applied) * blend;
+		let max_change = if (self.applied + change).abs() > self.applied.abs() {
+			self.max_rise_rate * delta_seconds
+		} else {
+			self.max_fall_rate * delta_seconds
+		};
+		self.applied += change.clamp(-max_change, max_change);
+	}
+
+	/// The force of `internal_force`, in its own frame, with the applied strength
+	pub(crate) fn apply_to_force(dynamics: Option<&Self>, internal_force: &InternalForce) -> Vec3 {
+		match dynamics {
+			Some(dynamics) => internal_force.get_naive_force() * dynamics.applied,
+			None => internal_force.compute_naive_force(),
+		}
+	}
+
+	/// The torque of `internal_torque`, in its own frame, with the applied strength
+	pub(crate) fn apply_to_torque(dynamics: Option<&Self>, internal_torque: &InternalTorque) -> Vec3 {
+		match dynamics {
+			Some(dynamics) => internal_torque.get_naive_torque() * dynamics.applied,
+			None => internal_torque.compute_naive_torque(),
+		}
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Steps every [ActuatorDynamics] towards its commanded strength, once per physics step.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn step_actuator_dynamics(
+		mut actuators: Query<(
+			&mut ActuatorDynamics,
+			Option<&InternalForce>,
+			Option<&InternalTorque>,
+		)>,
+		time: Res<Time>,
+	) {
+		for (mut dynamics, internal_force, internal_torque) in actuators.iter_mut() {
+			let command = match (internal_force, internal_torque) {
+				(Some(internal_force), _) => internal_force.get_strength(),
+				(None, Some(internal_torque)) => internal_torque.get_strength(),
+				(None, None) => 0.0,
+			};
+			dynamics.step(command, time.delta_seconds());
+		}
+	}
+}
@@ -0,0 +1,74 @@
+//! Reference frames that internal forces, torques and impulses are specified in.
+
+use bevy::ecs::system::SystemParam;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The frame of reference an [InternalForce](crate::InternalForce),
+/// [InternalTorque](crate::InternalTorque) or [InternalImpulse](crate::InternalImpulse)
+/// is specified in.
+///
+/// Only the *rotation* of the frame is used, the force is always applied at the child's position.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum InternalFrame {
+	/// World space, ignoring the rotation of the parent and child.
+	/// The force will not rotate as the parent rotates.
+	World,
+	/// The space of the [RigidBody] the child is attached to.
+	/// The force rotates with the parent, but ignores the rotation of the child.
+	ParentBody,
+	/// The space of the child entity itself, i.e. the parent's rotation combined with
+	/// the rotation of the child (and any intermediate entities) relative to the parent.
+	/// The force rotates with both the parent and the child, e.g. along a turret's barrel.
+	#[default]
+	ChildLocal,
+	/// The space of any other entity, using its [Rotation] if it has one (e.g. it is a [RigidBody] or [Collider]),
+	/// otherwise its [GlobalTransform].
+	/// If the entity doesn't exist (or has neither) the force isn't applied.
+	Entity(Entity),
+}
+
+impl InternalFrame {
+	/// Rotates `vector` from this frame into world space.
+	///
+	/// `parent_body_rotation` is the world space rotation of the [RigidBody],
+	/// and `child_relative_rotation` is the rotation of the child relative to that [RigidBody].
+	/// `entity_rotation` looks up the world space rotation of an [InternalFrame::Entity].
+	/// Returns [None] if this is an [InternalFrame::Entity] that can't be found.
+	pub fn to_world(
+		&self,
+		vector: Vec3,
+		parent_body_rotation: Quat,
+		child_relative_rotation: Quat,
+		entity_rotation: impl FnOnce(Entity) -> Option<Quat>,
+	) -> Option<Vec3> {
+		match self {
+			InternalFrame::World => Some(vector),
+			InternalFrame::ParentBody => Some(parent_body_rotation.mul_vec3(vector)),
+			InternalFrame::ChildLocal => {
+				Some((parent_body_rotation * child_relative_rotation).mul_vec3(vector))
+			}
+			InternalFrame::Entity(entity) => {
+				entity_rotation(*entity).map(|rotation| rotation.mul_vec3(vector))
+			}
+		}
+	}
+}
+
+/// Looks up the world space rotation of entities used as an [InternalFrame::Entity].
+#[derive(SystemParam)]
+pub(crate) struct EntityFrames<'w, 's> {
+	frames: Query<'w, 's, AnyOf<(&'static Rotation, &'static GlobalTransform)>>,
+}
+
+impl EntityFrames<'_, '_> {
+	/// Prefers the [Rotation] of `entity`, since a [GlobalTransform] lags behind the physics simulation.
+	pub fn rotation(&self, entity: Entity) -> Option<Quat> {
+		match self.frames.get(entity).ok()? {
+			(Some(rotation), _) => Some(rotation.0),
+			(None, Some(global_transform)) => Some(global_transform.to_scale_rotation_translation().1),
+			(None, None) => None,
+		}
+	}
+}
@@ -0,0 +1,275 @@
+//! Custom force models, which plug into the same frames and ledger as [InternalForce]s.
+
+use serde::{Deserialize, Serialize};
+
+use crate::frame::EntityFrames;
+use crate::gimbal::gimballed_rotation;
+use crate::hierarchy::{world_lever_arm, world_point, RigidBodyAncestors};
+use crate::prelude::*;
+
+/// Everything a [ForceGenerator] knows about its child, and the [RigidBody] ancestor it pushes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForceContext {
+	/// The child entity with the [ForceGenerator] component
+	pub child: Entity,
+	/// The [RigidBody] ancestor of the child
+	pub parent: Entity,
+	/// The world space [Position] of the parent
+	pub parent_position: Vec3,
+	/// The world space [Rotation] of the parent
+	pub parent_rotation: Quat,
+	/// The world space [LinearVelocity] of the parent
+	pub linear_velocity: Vec3,
+	/// The world space [AngularVelocity] of the parent
+	pub angular_velocity: Vec3,
+	/// The translation of the child relative to the parent, in the parent's local space
+	pub child_translation: Vec3,
+	/// The rotation of the child relative to the parent, including any [Gimbal] deflection
+	pub child_rotation: Quat,
+	/// The world space position of the child
+	pub child_position: Vec3,
+	/// The world space velocity of the child's position, from the parent's linear and angular velocity
+	pub child_velocity: Vec3,
+	/// How long the physics clock has been running, in seconds
+	pub elapsed_seconds: f32,
+	/// The length of the physics step, in seconds
+	pub delta_seconds: f32,
+}
+
+/// The force and torque a [ForceGenerator] produced for its child, which is also kept on the child for inspection.
+///
+/// Inserted automatically on every child with a registered [ForceGenerator].
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct GeneratedWrench {
+	/// In the space of [GeneratedWrench::frame]
+	pub force: Vec3,
+	/// In the space of [GeneratedWrench::frame]
+	pub torque: Vec3,
+	/// Where the force is applied, as an offset from the child's position (in meters),
+	/// in the space of [GeneratedWrench::frame]
+	pub point: Vec3,
+	/// The frame of reference that the force, torque and point are specified in
+	pub frame: InternalFrame,
+}
+
+impl GeneratedWrench {
+	/// Creates an empty wrench in `frame`, applied at the child's position
+	pub fn new(frame: InternalFrame) -> Self {
+		Self { frame, ..default() }
+	}
+
+	pub fn with_force(mut self, force: Vec3) -> Self {
+		self.force = force;
+		self
+	}
+
+	pub fn with_torque(mut self, torque: Vec3) -> Self {
+		self.torque = torque;
+		self
+	}
+
+	pub fn with_point(mut self, point: Vec3) -> Self {
+		self.point = point;
+		self
+	}
+}
+
+/// A custom model of the force a child exerts on its [RigidBody] ancestor, e.g. a magnet or a tractor beam.
+///
+/// Implement this on a component, then register it with [ParentingPlugin::with_force_generator].
+/// Every child with that component is then run during
+/// [ParentingSystemSet::PropagateInternalForces](crate::ParentingSystemSet::PropagateInternalForces),
+/// and the resulting [GeneratedWrench] is applied to its parent and recorded in its [InternalWrenchLedger],
+/// exactly like an [InternalForce] and [InternalTorque].
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d_parenting::prelude::*;
+/// use bevy_xpbd_3d_parenting::{ForceContext, ForceGenerator};
+///
+/// /// Pulls its parent towards the origin, like a spring
+/// #[derive(Component)]
+/// struct Tether {
+///   stiffness: f32,
+/// }
+///
+/// impl ForceGenerator for Tether {
+///   fn generate(&self, context: &ForceContext) -> GeneratedWrench {
+///     GeneratedWrench::new(InternalFrame::World).with_force(-context.child_position * self.stiffness)
+///   }
+/// }
+///
+/// let plugin = ParentingPlugin::new(Update).with_force_generator::<Tether>();
+/// ```
+pub trait ForceGenerator: Component {
+	/// Computes the force and torque that this child exerts on its parent
+	fn generate(&self, context: &ForceContext) -> GeneratedWrench;
+}
+
+impl crate::ParentingPlugin {
+	/// Runs the [ForceGenerator] `G` on every child with a `G` component.
+	/// Call this for each of your own [ForceGenerator]s
+	pub fn with_force_generator<G: ForceGenerator>(mut self) -> Self {
+		self.force_generators.push(|app, schedule| {
+			app.add_systems(
+				schedule,
+				(
+					Self::prepare_force_generator::<G>.in_set(crate::ParentingSystemSet::PrepareChildren),
+					Self::run_force_generator::<G>
+						.in_set(crate::ParentingSystemSet::PropagateInternalForces)
+						.before(Self::propagate_generated_wrenches),
+				),
+			);
+		});
+		self
+	}
+
+	/// Inserts a [GeneratedWrench] on every child with a `G` that doesn't have one yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_force_generator<G: ForceGenerator>(
+		mut commands: Commands,
+		generators: Query<Entity, (With<G>, Without<GeneratedWrench>)>,
+	) {
+		for entity in generators.iter() {
+			commands.entity(entity).insert(GeneratedWrench::default());
+		}
+	}
+
+	/// Writes the result of every child's `G` into its [GeneratedWrench].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn run_force_generator<G: ForceGenerator>(
+		mut generators: Query<(Entity, &G, Option<&Gimbal>, &mut GeneratedWrench), Without<RigidBody>>,
+		parents: Query<
+			(
+				&Position,
+				&Rotation,
+				&LinearVelocity,
+				&AngularVelocity,
+				&CenterOfMass,
+				&GlobalTransform,
+			),
+			With<RigidBody>,
+		>,
+		ancestors: RigidBodyAncestors,
+		time: Res<Time<Physics>>,
+	) {
+		for (child, generator, gimbal, mut generated) in generators.iter_mut() {
+			let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+				continue;
+			};
+			let Ok((
+				position,
+				rotation,
+				linear_velocity,
+				angular_velocity,
+				center_of_mass,
+				parent_global_transform,
+			)) = parents.get(rigid_body)
+			else {
+				continue;
+			};
+
+			let scale = parent_global_transform.compute_transform().scale;
+			let lever_arm = world_lever_arm(
+				child_relative_transform.translation,
+				scale,
+				rotation.0,
+				center_of_mass.0,
+			);
+			let context = ForceContext {
+				child,
+				parent: rigid_body,
+				parent_position: position.0,
+				parent_rotation: rotation.0,
+				linear_velocity: linear_velocity.0,
+				angular_velocity: angular_velocity.0,
+				child_translation: child_relative_transform.translation,
+				child_rotation: gimballed_rotation(child_relative_transform.rotation, gimbal),
+				child_position: world_point(
+					child_relative_transform.translation,
+					scale,
+					position.0,
+					rotation.0,
+				),
+				child_velocity: linear_velocity.0 + angular_velocity.0.cross(lever_arm),
+				elapsed_seconds: time.elapsed_seconds(),
+				delta_seconds: time.delta_seconds(),
+			};
+			*generated = generator.generate(&context);
+		}
+	}
+
+	/// Accumulates every child's [GeneratedWrench] into its parent's [InternalWrench].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn propagate_generated_wrenches(
+		mut parents: Query<
+			(
+				&mut InternalWrench,
+				Option<&mut InternalWrenchLedger>,
+				&CenterOfMass,
+				&Position,
+				&Rotation,
+				&GlobalTransform,
+			),
+			With<RigidBody>,
+		>,
+		children: Query<(Entity, &GeneratedWrench, Option<&Gimbal>), Without<RigidBody>>,
+		ancestors: RigidBodyAncestors,
+		entity_frames: EntityFrames,
+	) {
+		for (child, generated, gimbal) in children.iter() {
+			let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+				// reported by `diagnose`
+				continue;
+			};
+			let Ok((
+				mut internal_wrench,
+				ledger,
+				center_of_mass,
+				position,
+				rotation,
+				parent_global_transform,
+			)) = parents.get_mut(rigid_body)
+			else {
+				continue;
+			};
+
+			let child_rotation = gimballed_rotation(child_relative_transform.rotation, gimbal);
+			let to_world = |vector| {
+				generated
+					.frame
+					.to_world(vector, rotation.0, child_rotation, |entity| {
+						entity_frames.rotation(entity)
+					})
+			};
+			let (Some(force), Some(torque), Some(offset)) = (
+				to_world(generated.force),
+				to_world(generated.torque),
+				to_world(generated.point),
+			) else {
+				// reported by `diagnose`
+				continue;
+			};
+
+			let scale = parent_global_transform.compute_transform().scale;
+			let lever_arm = world_lever_arm(
+				child_relative_transform.translation,
+				scale,
+				rotation.0,
+				center_of_mass.0,
+			) + offset;
+			internal_wrench.apply_force_at_lever_arm(force, lever_arm);
+			internal_wrench.apply_torque(torque);
+			if let Some(mut ledger) = ledger {
+				let point = world_point(
+					child_relative_transform.translation,
+					scale,
+					position.0,
+					rotation.0,
+				) + offset;
+				ledger.record(child, force, point, lever_arm.cross(force) + torque);
+			}
+		}
+	}
+}
@@ -0,0 +1,162 @@
+//! Gimballing children, to steer by vectoring their thrust.
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Deflects a child's [InternalForce] and [InternalTorque] as if the child itself were rotated,
+/// without rotating its [Transform] (and so any [Collider]s), e.g. for thrust vectoring.
+///
+/// The deflection is a rotation around the child's local X axis (`deflection.x`) and local Z axis
+/// (`deflection.y`), which suits [Thruster]s pushing along their local Y axis.
+/// It follows the commanded deflection at no more than the slew rate,
+/// and always stays within a cone of `max_angle` radians.
+///
+/// Only forces and torques in the [InternalFrame::ChildLocal] frame are deflected,
+/// since forces in other frames don't rotate with the child either.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d_parenting::prelude::*;
+///
+/// // up to 10 degrees of deflection, slewing at 30 degrees per second
+/// let mut gimbal = Gimbal::new(10f32.to_radians(), 30f32.to_radians());
+/// gimbal.set_command(Vec2::new(1.0, 0.0));
+/// // clamped to the cone
+/// assert_eq!(gimbal.get_command(), Vec2::new(10f32.to_radians(), 0.0));
+/// ```
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct Gimbal {
+	/// The half-angle of the cone the deflection is limited to, in radians
+	max_angle: f32,
+	/// The maximum angular speed of the deflection, in radians per second
+	slew_rate: f32,
+	/// The commanded deflection, in radians
+	command: Vec2,
+	/// The current deflection, in radians
+	deflection: Vec2,
+	/// An entity whose [Transform::rotation] is deflected to match, e.g. the nozzle's mesh.
+	/// The deflection is applied on top of the rotation it was given, which is kept.
+	/// This shouldn't be the gimballed child itself, as that would deflect the force twice
+	pub visual: Option<Entity>,
+	/// The deflection last applied on top of the `visual` entity's rotation
+	#[serde(skip)]
+	visual_deflection: Quat,
+}
+
+impl Gimbal {
+	/// Creates an undeflected [Gimbal] limited to a cone of `max_angle` radians,
+	/// which moves at up to `slew_rate` radians per second
+	pub fn new(max_angle: f32, slew_rate: f32) -> Self {
+		Self {
+			max_angle: max_angle.max(0.0),
+			slew_rate: slew_rate.max(0.0),
+			command: Vec2::ZERO,
+			deflection: Vec2::ZERO,
+			visual: None,
+			visual_deflection: Quat::IDENTITY,
+		}
+	}
+
+	/// Deflects the `visual` entity's [Transform] to match, on top of its own rotation
+	pub fn with_visual(mut self, visual: Entity) -> Self {
+		self.visual = Some(visual);
+		self
+	}
+
+	pub fn get_max_angle(&self) -> f32 {
+		self.max_angle
+	}
+
+	pub fn get_slew_rate(&self) -> f32 {
+		self.slew_rate
+	}
+
+	pub fn set_slew_rate(&mut self, slew_rate: f32) {
+		self.slew_rate = slew_rate.max(0.0);
+	}
+
+	pub fn get_command(&self) -> Vec2 {
+		self.command
+	}
+
+	/// Sets the deflection to move towards, in radians, which is clamped to the cone
+	pub fn set_command(&mut self, command: Vec2) {
+		self.command = if command.is_finite() {
+			command.clamp_length_max(self.max_angle)
+		} else {
+			Vec2::ZERO
+		};
+	}
+
+	pub fn with_command(mut self, command: Vec2) -> Self {
+		self.set_command(command);
+		self
+	}
+
+	/// The current deflection, in radians
+	pub fn get_deflection(&self) -> Vec2 {
+		self.deflection
+	}
+
+	/// Immediately moves to the commanded deflection, ignoring the slew rate
+	pub fn snap_to_command(&mut self) {
+		self.deflection = self.command;
+	}
+
+	/// The rotation of the current deflection, in the child's local space
+	pub fn compute_rotation(&self) -> Quat {
+		Quat::from_scaled_axis(Vec3::new(self.deflection.x, 0.0, self.deflection.y))
+	}
+
+	/// Moves the deflection towards the command over `delta_seconds`
+	pub(crate) fn slew(&mut self, delta_seconds: f32) {
+		let remaining = self.command - self.deflection;
+		let max_step = self.slew_rate * delta_seconds;
+		self.deflection += remaining.clamp_length_max(max_step);
+	}
+}
+
+/// The rotation of a child relative to its [RigidBody] ancestor, deflected by its [Gimbal]
+pub(crate) fn gimballed_rotation(child_relative_rotation: Quat, gimbal: Option<&Gimbal>) -> Quat {
+	match gimbal {
+		Some(gimbal) => child_relative_rotation * gimbal.compute_rotation(),
+		None => child_relative_rotation,
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Moves every [Gimbal] towards its command over a single physics step.
+	/// This is automatically scheduled in the [PhysicsSchedule](bevy_xpbd_3d::PhysicsSchedule)
+	pub(crate) fn slew_gimbals(mut gimbals: Query<&mut Gimbal>, time: Res<Time>) {
+		for mut gimbal in gimbals.iter_mut() {
+			if gimbal.deflection != gimbal.command {
+				gimbal.slew(time.delta_seconds());
+			}
+		}
+	}
+
+	/// Rotates the visual entity of every [Gimbal] to match its deflection,
+	/// keeping the rest of the visual entity's rotation.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn rotate_gimbal_visuals(
+		mut gimbals: Query<(Entity, &mut Gimbal)>,
+		mut transforms: Query<&mut Transform>,
+	) {
+		for (entity, mut gimbal) in gimbals.iter_mut() {
+			let Some(visual) = gimbal.visual.filter(|visual| *visual != entity) else {
+				continue;
+			};
+			let deflection = gimbal.compute_rotation();
+			if deflection == gimbal.visual_deflection {
+				continue;
+			}
+			if let Ok(mut transform) = transforms.get_mut(visual) {
+				let rest = transform.rotation * gimbal.visual_deflection.inverse();
+				transform.rotation = (rest * deflection).normalize();
+				gimbal.visual_deflection = deflection;
+			}
+		}
+	}
+}
@@ -0,0 +1,68 @@
+//! Finding the [RigidBody] that a child entity exerts its forces on.
+
+use bevy::ecs::system::SystemParam;
+
+use crate::prelude::*;
+
+/// Walks up the bevy hierarchy from a child entity to its nearest [RigidBody] ancestor,
+/// so that children can be nested arbitrarily deep underneath the body they push on,
+/// e.g. thruster -> engine pod -> hull.
+#[derive(SystemParam)]
+pub(crate) struct RigidBodyAncestors<'w, 's> {
+	hierarchy:
+		Query<'w, 's, (Option<&'static Parent>, Option<&'static Transform>), Without<RigidBody>>,
+	bodies: Query<'w, 's, (), With<RigidBody>>,
+}
+
+impl RigidBodyAncestors<'_, '_> {
+	/// Returns the nearest [RigidBody] ancestor of `child`, along with the
+	/// [Transform] of `child` relative to that ancestor.
+	/// The relative transform is built from every intermediate entity's [Transform],
+	/// entities without a [Transform] are treated as [Transform::IDENTITY].
+	///
+	/// Returns [None] if there is no [RigidBody] ancestor.
+	pub fn find(&self, child: Entity) -> Option<(Entity, Transform)> {
+		let (mut parent, transform) = self.hierarchy.get(child).ok()?;
+		let mut relative_transform = transform.copied().unwrap_or_default();
+
+		loop {
+			let ancestor = parent?.get();
+			if self.bodies.contains(ancestor) {
+				return Some((ancestor, relative_transform));
+			}
+
+			let (ancestors_parent, ancestors_transform) = self.hierarchy.get(ancestor).ok()?;
+			if let Some(ancestors_transform) = ancestors_transform {
+				relative_transform = ancestors_transform.mul_transform(relative_transform);
+			}
+			parent = ancestors_parent;
+		}
+	}
+}
+
+/// Computes the world space lever arm of a point, relative to the center of mass of a [RigidBody].
+///
+/// `child_relative_translation` is the translation of the child relative to the body, so is in the
+/// body's scaled local space, while `center_of_mass` is in the body's unscaled local space (like [CenterOfMass]).
+///
+/// Passing the result to e.g. [ExternalForce::apply_force_at_point] with a center of mass of [Vec3::ZERO]
+/// gives the correct torque for a world space force.
+pub(crate) fn world_lever_arm(
+	child_relative_translation: Vec3,
+	body_scale: Vec3,
+	body_rotation: Quat,
+	center_of_mass: Vec3,
+) -> Vec3 {
+	body_rotation.mul_vec3(child_relative_translation * body_scale - center_of_mass)
+}
+
+/// The world space position of a child, given its translation relative to its [RigidBody]
+/// ancestor and that body's [Position].
+pub(crate) fn world_point(
+	child_relative_translation: Vec3,
+	body_scale: Vec3,
+	body_position: Vec3,
+	body_rotation: Quat,
+) -> Vec3 {
+	body_position + body_rotation.mul_vec3(child_relative_translation * body_scale)
+}
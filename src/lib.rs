@@ -6,11 +6,72 @@ use std::ops::{Deref, DerefMut};
 
 use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
+use bevy_xpbd_3d::plugins::prepare::PrepareSet;
 use bevy_xpbd_3d::prelude::*;
+use bevy_xpbd_3d::{PhysicsSchedule, PhysicsStepSet};
 use serde::{Deserialize, Serialize};
 
+mod acceleration;
+mod aero;
+mod allocation;
+mod buoyancy;
+mod controllers;
+mod diagnostics;
+mod dynamics;
+mod frame;
+mod generator;
+mod gimbal;
+mod hierarchy;
+mod motor;
+mod propellant;
+mod rotor;
+mod setup;
+mod thruster;
+#[cfg(feature = "timeline")]
+mod timeline;
+#[cfg(feature = "wheels")]
+mod wheel;
+mod wind;
+mod wrench;
+
+pub use acceleration::InternalAcceleration;
+pub use aero::{AeroSurface, AirDensity, LiftCurve};
+pub use allocation::{allocate, Actuator, AllocationLimits, ThrustAllocator};
+pub use buoyancy::{BuoyancyShape, BuoyancyVolume, Water, WaterSurface};
+pub use controllers::{AltitudeHold, AttitudeHold, HeadingHold, Pid, VelocityHold};
+pub use diagnostics::{validate, ParentingDiagnostic, ParentingDiagnostics, ParentingIssue};
+pub use dynamics::ActuatorDynamics;
+pub use frame::InternalFrame;
+pub use generator::{ForceContext, ForceGenerator, GeneratedWrench};
+pub use gimbal::Gimbal;
+pub use motor::VelocityMotor;
+pub use propellant::{PropellantFeed, PropellantTank, STANDARD_GRAVITY};
+pub use rotor::{Rotor, SpinDirection};
+pub use setup::{ParentComponent, ParentComponentsInserted};
+pub use thruster::Thruster;
+#[cfg(feature = "timeline")]
+pub use timeline::{
+	ForceKeyframe, ForceSample, ForceTimeline, ForceTimelineLoader, ForceTimelineLoaderError,
+	ForceTimelinePlayer, Interpolation,
+};
+#[cfg(feature = "wheels")]
+pub use wheel::{RaycastWheel, WheelContact};
+pub use wind::WindField;
+pub use wrench::{InternalWrench, InternalWrenchLedger, WrenchContribution};
+
 pub mod prelude {
-	pub use crate::{InternalForce, ParentingPlugin};
+	#[cfg(feature = "wheels")]
+	pub use crate::RaycastWheel;
+	pub use crate::{
+		ActuatorDynamics, AeroSurface, AllocationLimits, AltitudeHold, AttitudeHold, BuoyancyVolume,
+		GeneratedWrench, Gimbal, HeadingHold, InternalAcceleration, InternalForce, InternalFrame,
+		InternalImpulse, InternalTorque, InternalWrench, InternalWrenchLedger,
+		ParentComponentsInserted, ParentingDiagnostic, ParentingDiagnostics, ParentingIssue,
+		ParentingPlugin, PropellantFeed, PropellantTank, Rotor, SpinDirection, ThrustAllocator,
+		Thruster, VelocityHold, VelocityMotor, Water, WindField,
+	};
+	#[cfg(feature = "timeline")]
+	pub use crate::{ForceTimeline, ForceTimelinePlayer};
 	pub(crate) use bevy::prelude::*;
 	pub(crate) use bevy_xpbd_3d::prelude::*;
 }
@@ -21,12 +82,31 @@ pub struct ParentingPlugin {
 	/// This allows for properly scheduling systems correctly, 'undefined' behavior
 	/// occurs if this is set to a different schedule than [bevy_xpbd_3d::PhysicsPlugins] is running on.
 	bevy_xpbd_schedule: InternedScheduleLabel,
+	/// Adds the systems of each [ForceGenerator] registered with [ParentingPlugin::with_force_generator]
+	force_generators: Vec<fn(&mut App, InternedScheduleLabel)>,
 }
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum ParentingSystemSet {
-	ManuallyClearForces,
+	/// Inserts any components that children are missing, e.g. the [InternalForce] of a [Thruster].
+	/// Runs before [ParentingSystemSet::PrepareParents]
+	PrepareChildren,
+	/// Inserts any components that parents are missing, see [ParentComponentsInserted].
+	/// Runs before bevy_xpbd initializes new [RigidBody]s
+	PrepareParents,
+	/// Resets each parent's [InternalWrench] and [InternalWrenchLedger] from the previous frame
+	ResetInternalWrenches,
+	/// Writes the [InternalForce]s of children driven by the plugin, e.g. [Thruster]s.
+	/// Systems mutating these should be scheduled before this
+	UpdateInternalForces,
+	/// Accumulates every child's [InternalForce] and [InternalTorque] into its parent's [InternalWrench],
+	/// and applies every [InternalImpulse]
 	PropagateInternalForces,
+	/// Adds each parent's [InternalWrench] on top of its [ExternalForce] and [ExternalTorque]
+	/// for the duration of each physics step. Runs in the [PhysicsSchedule], before [PhysicsStepSet::BroadPhase]
+	ApplyInternalForces,
+	/// Updates the [ParentingDiagnostics], reporting every new [ParentingIssue]
+	Diagnose,
 }
 
 impl ParentingPlugin {
@@ -46,6 +126,7 @@ impl ParentingPlugin {
 	pub fn new(bevy_xpbd_schedule: impl ScheduleLabel) -> Self {
 		Self {
 			bevy_xpbd_schedule: bevy_xpbd_schedule.intern(),
+			force_generators: Vec::new(),
 		}
 	}
 }
@@ -56,30 +137,176 @@ impl Plugin for ParentingPlugin {
 		type PSS = ParentingSystemSet;
 
 		app
-			.add_systems(
+			.configure_sets(
+				self.bevy_xpbd_schedule,
+				(PSS::PrepareChildren, PSS::PrepareParents)
+					.chain()
+					.in_set(PrepareSet::PreInit),
+			)
+			.configure_sets(
 				self.bevy_xpbd_schedule,
 				(
-					Self::manually_clear_forces.in_set(PSS::ManuallyClearForces),
-					Self::propagate_internal_forces.in_set(PSS::PropagateInternalForces),
-					// #[cfg(feature = "debug")]
-					// helper_warnings,
+					PSS::ResetInternalWrenches,
+					PSS::UpdateInternalForces,
+					PSS::PropagateInternalForces,
+					PSS::Diagnose,
 				)
+					.chain()
 					.after(PhysicsSet::Prepare)
 					.before(PhysicsSet::StepSimulation),
 			)
-			.register_type::<InternalForce>();
+			.configure_sets(
+				PhysicsSchedule,
+				PSS::ApplyInternalForces.before(PhysicsStepSet::BroadPhase),
+			)
+			.add_systems(
+				self.bevy_xpbd_schedule,
+				(
+					(
+						Self::prepare_thrusters,
+						Self::prepare_aero_surfaces,
+						Self::prepare_buoyancy_volumes,
+						Self::prepare_rotors,
+						Self::prepare_internal_accelerations,
+						Self::prepare_velocity_motors,
+					)
+						.in_set(PSS::PrepareChildren),
+					(Self::prepare_parents, Self::prepare_controllers).in_set(PSS::PrepareParents),
+					Self::reset_internal_wrenches.in_set(PSS::ResetInternalWrenches),
+					(
+						(Self::allocate_thrust, Self::update_thrusters).chain(),
+						Self::rotate_gimbal_visuals,
+						Self::update_propellant_mass,
+						Self::update_aero_surfaces,
+						Self::update_buoyancy_volumes,
+						Self::update_rotors,
+						Self::update_internal_accelerations.after(Self::update_propellant_mass),
+						Self::update_velocity_motors.after(Self::update_propellant_mass),
+					)
+						.in_set(PSS::UpdateInternalForces),
+					(
+						Self::propagate_internal_forces,
+						Self::propagate_internal_torques,
+						Self::propagate_internal_impulses,
+						Self::propagate_generated_wrenches,
+					)
+						.in_set(PSS::PropagateInternalForces),
+					Self::diagnose.in_set(PSS::Diagnose),
+					// #[cfg(feature = "debug")]
+					// helper_warnings,
+				),
+			)
+			.add_systems(
+				PhysicsSchedule,
+				(
+					Self::apply_internal_forces.in_set(PSS::ApplyInternalForces),
+					Self::remove_internal_forces
+						.after(PhysicsStepSet::SpatialQuery)
+						.before(bevy_xpbd_3d::plugins::integrator::clear_forces_and_impulses),
+					// the propellant burnt during a step depends on the strength applied before it
+					(
+						(Self::consume_propellant, Self::step_actuator_dynamics).chain(),
+						Self::spool_rotors,
+						Self::slew_gimbals,
+						Self::run_controllers,
+					)
+						.after(bevy_xpbd_3d::plugins::integrator::clear_forces_and_impulses),
+				),
+			)
+			.register_type::<InternalForce>()
+			.register_type::<InternalTorque>()
+			.register_type::<InternalImpulse>()
+			.register_type::<InternalAcceleration>()
+			.register_type::<InternalFrame>()
+			.register_type::<GeneratedWrench>()
+			.register_type::<InternalWrench>()
+			.register_type::<InternalWrenchLedger>()
+			.register_type::<ParentComponent>()
+			.register_type::<ParentingIssue>()
+			.register_type::<Thruster>()
+			.register_type::<ActuatorDynamics>()
+			.register_type::<PropellantTank>()
+			.register_type::<PropellantFeed>()
+			.register_type::<Gimbal>()
+			.register_type::<ThrustAllocator>()
+			.register_type::<AllocationLimits>()
+			.register_type::<Pid>()
+			.register_type::<AttitudeHold>()
+			.register_type::<HeadingHold>()
+			.register_type::<AltitudeHold>()
+			.register_type::<VelocityHold>()
+			.register_type::<AeroSurface>()
+			.register_type::<LiftCurve>()
+			.register_type::<AirDensity>()
+			.register_type::<BuoyancyVolume>()
+			.register_type::<BuoyancyShape>()
+			.register_type::<Rotor>()
+			.register_type::<SpinDirection>()
+			.register_type::<VelocityMotor>()
+			.add_event::<ParentComponentsInserted>()
+			.add_event::<ParentingDiagnostic>()
+			.init_resource::<ParentingDiagnostics>()
+			.init_resource::<AirDensity>()
+			.init_resource::<Water>()
+			.init_resource::<WindField>();
+
+		for add_force_generator in self.force_generators.iter() {
+			add_force_generator(app, self.bevy_xpbd_schedule);
+		}
+
+		#[cfg(feature = "wheels")]
+		app
+			.add_systems(
+				self.bevy_xpbd_schedule,
+				(
+					Self::prepare_wheels.in_set(PSS::PrepareChildren),
+					Self::update_wheels
+						.run_if(resource_exists::<SpatialQueryPipeline>)
+						.in_set(PSS::UpdateInternalForces),
+				),
+			)
+			.register_type::<RaycastWheel>()
+			.register_type::<WheelContact>();
+
+		#[cfg(feature = "timeline")]
+		app
+			.add_systems(
+				self.bevy_xpbd_schedule,
+				(
+					Self::prepare_force_timelines.in_set(PSS::PrepareChildren),
+					Self::play_force_timelines.in_set(PSS::UpdateInternalForces),
+				),
+			)
+			.add_systems(
+				PhysicsSchedule,
+				Self::advance_force_timelines
+					.after(bevy_xpbd_3d::plugins::integrator::clear_forces_and_impulses),
+			)
+			.register_type::<ForceTimelinePlayer>()
+			.register_type::<ForceKeyframe>()
+			.register_type::<Interpolation>();
+	}
+
+	#[cfg(feature = "timeline")]
+	fn finish(&self, app: &mut App) {
+		// assets can only be registered once the `AssetPlugin` has been built, if the app has one at all
+		if app.world.contains_resource::<AssetServer>() {
+			app
+				.init_asset::<ForceTimeline>()
+				.init_asset_loader::<ForceTimelineLoader>();
+		}
 	}
 }
 
 /// Synced with parents
 #[derive(Reflect, Component, Debug, Clone, Copy, Serialize, Deserialize)]
 #[reflect(Component)]
-pub enum InternalForce {
-	/// A force that is applied in the global space of the parent entity.
-	Global { force: Vec3, strength: f32 },
-	/// A force that is applied in the local space of the parent entity,
-	/// relative to the child entity.
-	Local { force: Vec3, strength: f32 },
+pub struct InternalForce {
+	/// The force, *without* the strength applied, in the space of [InternalForce::frame]
+	pub force: Vec3,
+	pub strength: f32,
+	/// The frame of reference that [InternalForce::force] is specified in
+	pub frame: InternalFrame,
 }
 
 impl Default for InternalForce {
@@ -92,26 +319,21 @@ impl Deref for InternalForce {
 	type Target = Vec3;
 
 	fn deref(&self) -> &Self::Target {
-		match self {
-			InternalForce::Global { force, .. } => force,
-			InternalForce::Local { force, .. } => force,
-		}
+		&self.force
 	}
 }
 
 impl DerefMut for InternalForce {
 	fn deref_mut(&mut self) -> &mut Self::Target {
-		match self {
-			InternalForce::Global { force, .. } => force,
-			InternalForce::Local { force, .. } => force,
-		}
+		&mut self.force
 	}
 }
 
 impl InternalForce {
-	pub const ZERO: Self = InternalForce::Local {
+	pub const ZERO: Self = InternalForce {
 		force: Vec3::ZERO,
 		strength: 1.0,
+		frame: InternalFrame::ChildLocal,
 	};
 
 	pub const DEFAULT: Self = Self::ZERO;
@@ -121,24 +343,15 @@ impl InternalForce {
 	}
 
 	pub fn get_strength(&self) -> f32 {
-		match self {
-			InternalForce::Global { strength, .. } => *strength,
-			InternalForce::Local { strength, .. } => *strength,
-		}
+		self.strength
 	}
 
 	pub fn get_mut_strength(&mut self) -> &mut f32 {
-		match self {
-			InternalForce::Global { strength, .. } => strength,
-			InternalForce::Local { strength, .. } => strength,
-		}
+		&mut self.strength
 	}
 
 	pub fn set_strength(&mut self, strength: f32) {
-		match self {
-			InternalForce::Global { strength: s, .. } => *s = strength,
-			InternalForce::Local { strength: s, .. } => *s = strength,
-		}
+		self.strength = strength;
 	}
 
 	pub fn with_strength(mut self, strength: f32) -> Self {
@@ -146,29 +359,54 @@ impl InternalForce {
 		self
 	}
 
-	/// Creates an [InternalForce] that operates in the local space of the parent entity.
+	pub fn get_frame(&self) -> InternalFrame {
+		self.frame
+	}
+
+	pub fn set_frame(&mut self, frame: InternalFrame) {
+		self.frame = frame;
+	}
+
+	pub fn with_frame(mut self, frame: InternalFrame) -> Self {
+		self.set_frame(frame);
+		self
+	}
+
+	/// Creates an [InternalForce] in the given [InternalFrame].
 	/// By default, the strength is 1.0.
-	///
-	/// Also see [Self::new_forward_right_up]
-	pub fn new_local(force: Vec3) -> Self {
-		InternalForce::Local {
+	pub fn new_in_frame(frame: InternalFrame, force: Vec3) -> Self {
+		InternalForce {
 			force,
 			strength: 1.0,
+			frame,
 		}
 	}
 
+	/// Creates an [InternalForce] that operates in the local space of the child entity,
+	/// see [InternalFrame::ChildLocal].
+	/// By default, the strength is 1.0.
+	///
+	/// Also see [Self::new_local_forward_right_up]
+	pub fn new_local(force: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::ChildLocal, force)
+	}
+
 	/// See [InternalForce::new_local]
 	pub fn new_relative(force: Vec3) -> Self {
 		Self::new_local(force)
 	}
 
-	/// Creates an [InternalForce] that operates in the global space of the parent entity.
+	/// Creates an [InternalForce] that operates in the space of the parent [RigidBody],
+	/// ignoring the rotation of the child entity, see [InternalFrame::ParentBody].
+	/// By default, the strength is 1.0.
+	pub fn new_parent_body(force: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::ParentBody, force)
+	}
+
+	/// Creates an [InternalForce] that operates in global space, see [InternalFrame::World].
 	/// By default, the strength is 1.0.
 	pub fn new_global(force: Vec3) -> Self {
-		InternalForce::Global {
-			force,
-			strength: 1.0,
-		}
+		Self::new_in_frame(InternalFrame::World, force)
 	}
 
 	/// See [InternalForce::new_global]
@@ -176,7 +414,14 @@ impl InternalForce {
 		Self::new_global(force)
 	}
 
-	/// Creates an [InternalForce] that operates in the local space of the parent entity,
+	/// Creates an [InternalForce] that operates in the space of another entity,
+	/// see [InternalFrame::Entity].
+	/// By default, the strength is 1.0.
+	pub fn new_in_frame_of(entity: Entity, force: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::Entity(entity), force)
+	}
+
+	/// Creates an [InternalForce] that operates in the local space of the child entity,
 	/// with the force being forward, right, and up. This assumes forward is in the -Z
 	/// direction, right is in the +X direction, and up is in the +Y direction.
 	///
@@ -186,79 +431,661 @@ impl InternalForce {
 	}
 
 	/// Returns a [Vec3] representing the force, *without* the strength applied.
-	/// This is naive because it may be global or local.
-	/// To work out if the force is global or local, `match` on the `InternalForce`.
+	/// This is naive because it is in the space of [InternalForce::frame], not global space.
 	pub fn get_naive_force(&self) -> Vec3 {
 		**self
 	}
 
 	/// Returns a [Vec3] representing the force, *with* the strength applied.
-	/// This is naive because it may be global or local.
-	/// To work out if the force is global or local, `match` on the `InternalForce`.
+	/// This is naive because it is in the space of [InternalForce::frame], not global space.
 	pub fn compute_naive_force(&self) -> Vec3 {
 		self.get_naive_force() * self.get_strength()
 	}
 }
 
+/// Synced with parents, like [InternalForce] but applies a pure torque
+/// (no net force) through the parent's [ExternalTorque].
+#[derive(Reflect, Component, Debug, Clone, Copy, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct InternalTorque {
+	/// The torque, *without* the strength applied, in the space of [InternalTorque::frame]
+	pub torque: Vec3,
+	pub strength: f32,
+	/// The frame of reference that [InternalTorque::torque] is specified in
+	pub frame: InternalFrame,
+}
+
+impl Default for InternalTorque {
+	fn default() -> Self {
+		InternalTorque::DEFAULT
+	}
+}
+
+impl Deref for InternalTorque {
+	type Target = Vec3;
+
+	fn deref(&self) -> &Self::Target {
+		&self.torque
+	}
+}
+
+impl DerefMut for InternalTorque {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.torque
+	}
+}
+
+impl InternalTorque {
+	pub const ZERO: Self = InternalTorque {
+		torque: Vec3::ZERO,
+		strength: 1.0,
+		frame: InternalFrame::ChildLocal,
+	};
+
+	pub const DEFAULT: Self = Self::ZERO;
+
+	pub const fn default() -> Self {
+		Self::DEFAULT
+	}
+
+	pub fn get_strength(&self) -> f32 {
+		self.strength
+	}
+
+	pub fn get_mut_strength(&mut self) -> &mut f32 {
+		&mut self.strength
+	}
+
+	pub fn set_strength(&mut self, strength: f32) {
+		self.strength = strength;
+	}
+
+	pub fn with_strength(mut self, strength: f32) -> Self {
+		self.set_strength(strength);
+		self
+	}
+
+	pub fn get_frame(&self) -> InternalFrame {
+		self.frame
+	}
+
+	pub fn set_frame(&mut self, frame: InternalFrame) {
+		self.frame = frame;
+	}
+
+	pub fn with_frame(mut self, frame: InternalFrame) -> Self {
+		self.set_frame(frame);
+		self
+	}
+
+	/// Creates an [InternalTorque] in the given [InternalFrame].
+	/// By default, the strength is 1.0.
+	pub fn new_in_frame(frame: InternalFrame, torque: Vec3) -> Self {
+		InternalTorque {
+			torque,
+			strength: 1.0,
+			frame,
+		}
+	}
+
+	/// Creates an [InternalTorque] that operates in the local space of the child entity,
+	/// see [InternalFrame::ChildLocal].
+	/// The torque is an axis-angle vector, so e.g. `Vec3::Y` spins counter-clockwise about the
+	/// local up axis. By default, the strength is 1.0.
+	pub fn new_local(torque: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::ChildLocal, torque)
+	}
+
+	/// See [InternalTorque::new_local]
+	pub fn new_relative(torque: Vec3) -> Self {
+		Self::new_local(torque)
+	}
+
+	/// Creates an [InternalTorque] that operates in the space of the parent [RigidBody],
+	/// see [InternalFrame::ParentBody].
+	/// By default, the strength is 1.0.
+	pub fn new_parent_body(torque: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::ParentBody, torque)
+	}
+
+	/// Creates an [InternalTorque] that operates in global space, see [InternalFrame::World].
+	/// By default, the strength is 1.0.
+	pub fn new_global(torque: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::World, torque)
+	}
+
+	/// See [InternalTorque::new_global]
+	pub fn new_absolute(torque: Vec3) -> Self {
+		Self::new_global(torque)
+	}
+
+	/// Creates an [InternalTorque] that operates in the space of another entity,
+	/// see [InternalFrame::Entity].
+	/// By default, the strength is 1.0.
+	pub fn new_in_frame_of(entity: Entity, torque: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::Entity(entity), torque)
+	}
+
+	/// Returns a [Vec3] representing the torque, *without* the strength applied.
+	/// This is naive because it is in the space of [InternalTorque::frame], not global space.
+	pub fn get_naive_torque(&self) -> Vec3 {
+		**self
+	}
+
+	/// Returns a [Vec3] representing the torque, *with* the strength applied.
+	/// This is naive because it is in the space of [InternalTorque::frame], not global space.
+	pub fn compute_naive_torque(&self) -> Vec3 {
+		self.get_naive_torque() * self.get_strength()
+	}
+}
+
+/// A one-shot impulse delivered to the parent through its [ExternalImpulse],
+/// at the position of the child entity.
+///
+/// Once the impulse has been applied it is cleared back to [Vec3::ZERO],
+/// so setting it again (e.g. every time a weapon fires) applies another impulse.
+/// Unlike [InternalForce], the result does not depend on the physics timestep.
+#[derive(Reflect, Component, Debug, Clone, Copy, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct InternalImpulse {
+	/// The impulse waiting to be applied, in the space of [InternalImpulse::frame]
+	pub impulse: Vec3,
+	/// The frame of reference that [InternalImpulse::impulse] is specified in
+	pub frame: InternalFrame,
+}
+
+impl Default for InternalImpulse {
+	fn default() -> Self {
+		InternalImpulse::DEFAULT
+	}
+}
+
+impl Deref for InternalImpulse {
+	type Target = Vec3;
+
+	fn deref(&self) -> &Self::Target {
+		&self.impulse
+	}
+}
+
+impl DerefMut for InternalImpulse {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.impulse
+	}
+}
+
+impl InternalImpulse {
+	pub const ZERO: Self = InternalImpulse {
+		impulse: Vec3::ZERO,
+		frame: InternalFrame::ChildLocal,
+	};
+
+	pub const DEFAULT: Self = Self::ZERO;
+
+	pub const fn default() -> Self {
+		Self::DEFAULT
+	}
+
+	/// Creates an [InternalImpulse] in the given [InternalFrame].
+	pub fn new_in_frame(frame: InternalFrame, impulse: Vec3) -> Self {
+		InternalImpulse { impulse, frame }
+	}
+
+	/// Creates an [InternalImpulse] that operates in the local space of the child entity,
+	/// see [InternalFrame::ChildLocal].
+	pub fn new_local(impulse: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::ChildLocal, impulse)
+	}
+
+	/// See [InternalImpulse::new_local]
+	pub fn new_relative(impulse: Vec3) -> Self {
+		Self::new_local(impulse)
+	}
+
+	/// Creates an [InternalImpulse] that operates in the space of the parent [RigidBody],
+	/// see [InternalFrame::ParentBody].
+	pub fn new_parent_body(impulse: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::ParentBody, impulse)
+	}
+
+	/// Creates an [InternalImpulse] that operates in global space, see [InternalFrame::World].
+	pub fn new_global(impulse: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::World, impulse)
+	}
+
+	/// See [InternalImpulse::new_global]
+	pub fn new_absolute(impulse: Vec3) -> Self {
+		Self::new_global(impulse)
+	}
+
+	/// Creates an [InternalImpulse] that operates in the space of another entity,
+	/// see [InternalFrame::Entity].
+	pub fn new_in_frame_of(entity: Entity, impulse: Vec3) -> Self {
+		Self::new_in_frame(InternalFrame::Entity(entity), impulse)
+	}
+
+	/// Queues another impulse, replacing any impulse that has not been applied yet.
+	/// Keeps the same [InternalFrame].
+	pub fn fire(&mut self, impulse: Vec3) {
+		**self = impulse;
+	}
+
+	/// Returns `true` if there is no impulse waiting to be applied.
+	pub fn is_cleared(&self) -> bool {
+		**self == Vec3::ZERO
+	}
+
+	/// Discards any impulse waiting to be applied.
+	/// This is done automatically after the impulse is applied.
+	pub fn clear(&mut self) {
+		**self = Vec3::ZERO;
+	}
+}
+
 mod systems {
+	use bevy::ecs::entity::EntityHashMap;
+
+	use crate::frame::EntityFrames;
+	use crate::gimbal::gimballed_rotation;
+	use crate::hierarchy::{world_lever_arm, world_point, RigidBodyAncestors};
 	use crate::prelude::*;
+	use crate::setup::ParentComponent;
 	impl super::ParentingPlugin {
-		/// Mutates parent's [`ExternalForce`] component depending on it's
-		/// children that are not [`RigidBody`]'s but have an [`InternalForce`] component.
+		/// Inserts any [`ParentComponent`]s that a [`RigidBody`] is missing, but that its
+		/// descendants with an [`InternalForce`], [`InternalTorque`], [`InternalImpulse`] or [`GeneratedWrench`] need.
+		/// Sends a [`ParentComponentsInserted`] event for each parent, so that this isn't silent.
+		/// This is automatically scheduled in [ParentingPlugin]
+		pub(super) fn prepare_parents(
+			mut commands: Commands,
+			children: Query<
+				(
+					Entity,
+					Has<InternalForce>,
+					Has<InternalTorque>,
+					Has<InternalImpulse>,
+					Has<GeneratedWrench>,
+				),
+				(
+					Or<(
+						With<InternalForce>,
+						With<InternalTorque>,
+						With<InternalImpulse>,
+						With<GeneratedWrench>,
+					)>,
+					Without<RigidBody>,
+				),
+			>,
+			parents: Query<
+				(
+					Has<ExternalForce>,
+					Has<ExternalTorque>,
+					Has<ExternalImpulse>,
+					Has<InternalWrench>,
+					Has<InternalWrenchLedger>,
+				),
+				With<RigidBody>,
+			>,
+			ancestors: RigidBodyAncestors,
+			mut inserted_events: EventWriter<ParentComponentsInserted>,
+		) {
+			let mut missing: EntityHashMap<Vec<ParentComponent>> = EntityHashMap::default();
+			for (child, has_force, has_torque, has_impulse, has_generated) in children.iter() {
+				// a generated wrench can have both a force and a torque
+				let has_force = has_force || has_generated;
+				let Some((rigid_body, _)) = ancestors.find(child) else {
+					continue;
+				};
+				let Ok((
+					has_external_force,
+					has_external_torque,
+					has_external_impulse,
+					has_wrench,
+					has_ledger,
+				)) = parents.get(rigid_body)
+				else {
+					continue;
+				};
+
+				let needed = [
+					(
+						has_force && !has_external_force,
+						ParentComponent::ExternalForce,
+					),
+					(
+						(has_force || has_torque) && !has_external_torque,
+						ParentComponent::ExternalTorque,
+					),
+					(
+						has_impulse && !has_external_impulse,
+						ParentComponent::ExternalImpulse,
+					),
+					(
+						(has_force || has_torque) && !has_wrench,
+						ParentComponent::InternalWrench,
+					),
+					(
+						(has_force || has_torque) && !has_ledger,
+						ParentComponent::InternalWrenchLedger,
+					),
+				];
+				for (is_missing, component) in needed {
+					if !is_missing {
+						continue;
+					}
+					// only parents that are actually missing something are tracked
+					let components = missing.entry(rigid_body).or_default();
+					if !components.contains(&component) {
+						components.push(component);
+					}
+				}
+			}
+
+			for (parent, components) in missing {
+				let mut parent_commands = commands.entity(parent);
+				for component in components.iter() {
+					match component {
+						ParentComponent::ExternalForce => {
+							parent_commands.insert(ExternalForce::ZERO.with_persistence(false))
+						}
+						ParentComponent::ExternalTorque => {
+							parent_commands.insert(ExternalTorque::ZERO.with_persistence(false))
+						}
+						ParentComponent::ExternalImpulse => {
+							parent_commands.insert(ExternalImpulse::ZERO.with_persistence(false))
+						}
+						ParentComponent::InternalWrench => parent_commands.insert(InternalWrench::default()),
+						ParentComponent::InternalWrenchLedger => {
+							parent_commands.insert(InternalWrenchLedger::default())
+						}
+						ParentComponent::ThrustAllocator => parent_commands.insert(ThrustAllocator::default()),
+					};
+				}
+
+				info!(
+					"Inserted {:?} on parent {:?}, which its children need to exert forces on it",
+					components, parent
+				);
+				inserted_events.send(ParentComponentsInserted { parent, components });
+			}
+		}
+
+		/// Resets each parent's [`InternalWrench`] and [`InternalWrenchLedger`] from the previous frame,
+		/// so that they can be accumulated again.
+		/// This is automatically scheduled in [ParentingPlugin]
+		pub(super) fn reset_internal_wrenches(
+			mut parents: Query<(&mut InternalWrench, Option<&mut InternalWrenchLedger>)>,
+		) {
+			for (mut internal_wrench, ledger) in parents.iter_mut() {
+				#[cfg(feature = "debug")]
+				trace!("Resetting previous internal wrench {:?}", internal_wrench);
+				internal_wrench.reset();
+				if let Some(mut ledger) = ledger {
+					ledger.clear();
+				}
+			}
+		}
+
+		/// Mutates parent's [`InternalWrench`] component depending on it's
+		/// descendants that are not [`RigidBody`]'s but have an [`InternalForce`] component.
+		/// Each force is applied to the nearest [`RigidBody`] ancestor.
 		/// This is automatically scheduled in [ParentingPlugin]
 		pub(super) fn propagate_internal_forces(
-			mut parents: Query<(&mut ExternalForce, &CenterOfMass, &GlobalTransform), With<RigidBody>>,
+			mut parents: Query<
+				(
+					&mut InternalWrench,
+					Option<&mut InternalWrenchLedger>,
+					&CenterOfMass,
+					&Position,
+					&Rotation,
+					&GlobalTransform,
+				),
+				With<RigidBody>,
+			>,
+			children: Query<
+				(
+					Entity,
+					&InternalForce,
+					Option<&Gimbal>,
+					Option<&ActuatorDynamics>,
+				),
+				Without<RigidBody>,
+			>,
+			ancestors: RigidBodyAncestors,
+			entity_frames: EntityFrames,
+		) {
+			for (child, internal_force, gimbal, dynamics) in children.iter() {
+				let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+					// reported by `diagnose`
+					continue;
+				};
+				let Ok((
+					mut internal_wrench,
+					ledger,
+					center_of_mass,
+					parent_position,
+					parent_rotation,
+					parent_global_transform,
+				)) = parents.get_mut(rigid_body)
+				else {
+					// the `InternalWrench` is inserted by `prepare_parents`,
+					// and a missing `CenterOfMass` is reported by `diagnose`
+					continue;
+				};
+
+				// bevy_xpbd doesn't track scale, which rarely changes, so the (possibly lagging)
+				// `GlobalTransform` is only used for the scale
+				let parent_scale = parent_global_transform.compute_transform().scale;
+				let internal_point = world_lever_arm(
+					child_relative_transform.translation,
+					parent_scale,
+					parent_rotation.0,
+					center_of_mass.0,
+				);
+
+				let Some(internal_force) = internal_force.frame.to_world(
+					ActuatorDynamics::apply_to_force(dynamics, internal_force),
+					parent_rotation.0,
+					gimballed_rotation(child_relative_transform.rotation, gimbal),
+					|entity| entity_frames.rotation(entity),
+				) else {
+					// reported by `diagnose`
+					continue;
+				};
+
+				// the meat of the whole library
+				internal_wrench.apply_force_at_lever_arm(internal_force, internal_point);
+				if let Some(mut ledger) = ledger {
+					let point = world_point(
+						child_relative_transform.translation,
+						parent_scale,
+						parent_position.0,
+						parent_rotation.0,
+					);
+					ledger.record(
+						child,
+						internal_force,
+						point,
+						internal_point.cross(internal_force),
+					);
+				}
+
+				#[cfg(feature = "debug")]
+				debug!(
+					"Applying internal force {:?} at point {:?}, resulting in {:?}",
+					internal_force, internal_point, internal_wrench
+				);
+			}
+		}
+
+		/// Mutates parent's [`InternalWrench`] component depending on it's
+		/// descendants that are not [`RigidBody`]'s but have an [`InternalTorque`] component.
+		/// Each torque is applied to the nearest [`RigidBody`] ancestor.
+		/// This is automatically scheduled in [ParentingPlugin]
+		pub(super) fn propagate_internal_torques(
+			mut parents: Query<
+				(
+					&mut InternalWrench,
+					Option<&mut InternalWrenchLedger>,
+					&Position,
+					&Rotation,
+					&GlobalTransform,
+				),
+				With<RigidBody>,
+			>,
 			children: Query<
-				(&Parent, &InternalForce, &Transform),
-				(Without<RigidBody>, Without<ExternalForce>),
+				(
+					Entity,
+					&InternalTorque,
+					Option<&Gimbal>,
+					Option<&ActuatorDynamics>,
+				),
+				Without<RigidBody>,
+			>,
+			ancestors: RigidBodyAncestors,
+			entity_frames: EntityFrames,
+		) {
+			for (child, internal_torque, gimbal, dynamics) in children.iter() {
+				let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+					// reported by `diagnose`
+					continue;
+				};
+				let Ok((
+					mut internal_wrench,
+					ledger,
+					parent_position,
+					parent_rotation,
+					parent_global_transform,
+				)) = parents.get_mut(rigid_body)
+				else {
+					// the `InternalWrench` is inserted by `prepare_parents`
+					continue;
+				};
+
+				let Some(internal_torque) = internal_torque.frame.to_world(
+					ActuatorDynamics::apply_to_torque(dynamics, internal_torque),
+					parent_rotation.0,
+					gimballed_rotation(child_relative_transform.rotation, gimbal),
+					|entity| entity_frames.rotation(entity),
+				) else {
+					// reported by `diagnose`
+					continue;
+				};
+
+				internal_wrench.apply_torque(internal_torque);
+				if let Some(mut ledger) = ledger {
+					let point = world_point(
+						child_relative_transform.translation,
+						parent_global_transform.compute_transform().scale,
+						parent_position.0,
+						parent_rotation.0,
+					);
+					ledger.record(child, Vec3::ZERO, point, internal_torque);
+				}
+
+				#[cfg(feature = "debug")]
+				debug!(
+					"Applying internal torque {:?}, resulting in {:?}",
+					internal_torque, internal_wrench
+				);
+			}
+		}
+
+		/// Mutates parent's [`ExternalImpulse`] component depending on it's
+		/// descendants that are not [`RigidBody`]'s but have a non-zero [`InternalImpulse`] component,
+		/// then clears the [`InternalImpulse`].
+		/// Each impulse is applied to the nearest [`RigidBody`] ancestor.
+		/// This is automatically scheduled in [ParentingPlugin]
+		pub(super) fn propagate_internal_impulses(
+			mut parents: Query<
+				(
+					&mut ExternalImpulse,
+					&CenterOfMass,
+					&Rotation,
+					&GlobalTransform,
+				),
+				With<RigidBody>,
 			>,
+			mut children: Query<(Entity, &mut InternalImpulse), Without<RigidBody>>,
+			ancestors: RigidBodyAncestors,
+			entity_frames: EntityFrames,
 		) {
-			for (collider_parent, internal_force, child_relative_transform) in children.iter() {
-				if let Ok((mut parents_force, center_of_mass, parent_global_transform)) =
-					parents.get_mut(collider_parent.get())
+			for (child, mut internal_impulse) in children.iter_mut() {
+				if internal_impulse.is_cleared() {
+					continue;
+				}
+				let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+					// reported by `diagnose`
+					continue;
+				};
+				if let Ok((mut parents_impulse, center_of_mass, parent_rotation, parent_global_transform)) =
+					parents.get_mut(rigid_body)
 				{
-					if parents_force.persistent {
-						warn!("A child entity (with an `InternalForce` but no `RigidBody`) is a child of a RigidBody entity with a persistent ExternalForce. \
-								This is not supported, as child entities' `ExternalForce` is updated every (physics) frame by the `ParentingPlugin`");
+					if parents_impulse.persistent {
+						// not supported, as the impulse would be applied every (physics) frame,
+						// reported by `diagnose`
 					} else {
-						let internal_point = child_relative_transform.translation;
-
-						let internal_force = match internal_force {
-							InternalForce::Global { force, strength } => *force * *strength,
-							InternalForce::Local { force, strength } => {
-								let parent_space_force = child_relative_transform.rotation.mul_vec3(*force * *strength);
-								parent_global_transform.compute_transform().rotation.mul_vec3(parent_space_force)
-							}
-						};
-
-						#[cfg(feature = "debug")]
-						let previous_parents_force = *parents_force;
+						// bevy_xpbd doesn't track scale, which rarely changes, so the (possibly lagging)
+						// `GlobalTransform` is only used for the scale
+						let internal_point = world_lever_arm(
+							child_relative_transform.translation,
+							parent_global_transform.compute_transform().scale,
+							parent_rotation.0,
+							center_of_mass.0,
+						);
 
-						// the meat of the whole library
-						parents_force.apply_force_at_point(internal_force, internal_point, center_of_mass.0);
+						let Some(impulse) = internal_impulse.frame.to_world(
+							internal_impulse.impulse,
+							parent_rotation.0,
+							child_relative_transform.rotation,
+							|entity| entity_frames.rotation(entity),
+						) else {
+							// reported by `diagnose`
+							continue;
+						};
 
-						#[cfg(feature = "debug")]
-						parents_force.set_changed();
+						// the point is already relative to the center of mass, in world space
+						parents_impulse.apply_impulse_at_point(impulse, internal_point, Vec3::ZERO);
 
 						#[cfg(feature = "debug")]
 						debug!(
-							"Applying internal force {:?} at point {:?} on existing force {:?}, resulting in {:?}",
-							internal_force, internal_point, previous_parents_force, parents_force
+							"Applying internal impulse {:?} at point {:?}, resulting in {:?}",
+							impulse, internal_point, parents_impulse
 						);
+
+						internal_impulse.clear();
 					}
-				} else {
-					warn!("The parent of an entity with `InternalForce` points to a non-`RigidBody` entity");
 				};
 			}
 		}
 
-		pub(super) fn manually_clear_forces(mut external_forces: Query<&mut ExternalForce>) {
-			for mut external_force in external_forces.iter_mut() {
-				if !external_force.persistent {
-					#[cfg(feature = "debug")]
-					trace!("Manually clearing external force {:?}", external_force);
-					external_force.clear();
-				}
+		/// Adds each parent's [`InternalWrench`] on top of its [`ExternalForce`] and [`ExternalTorque`],
+		/// right before a physics step.
+		/// This is automatically scheduled in the [`PhysicsSchedule`]
+		pub(super) fn apply_internal_forces(
+			mut parents: Query<(&mut InternalWrench, &mut ExternalForce, &mut ExternalTorque)>,
+		) {
+			for (mut internal_wrench, mut external_force, mut external_torque) in parents.iter_mut() {
+				internal_wrench.apply_to(&mut external_force, &mut external_torque);
+
+				#[cfg(feature = "debug")]
+				trace!(
+					"Applied internal wrench {:?}, resulting in {:?} and {:?}",
+					internal_wrench,
+					external_force,
+					external_torque
+				);
+			}
+		}
+
+		/// Takes each parent's [`InternalWrench`] back off its [`ExternalForce`] and [`ExternalTorque`]
+		/// straight after a physics step, before bevy_xpbd clears the non-persistent ones,
+		/// so that they only ever hold the forces applied by the user.
+		/// This is automatically scheduled in the [`PhysicsSchedule`]
+		pub(super) fn remove_internal_forces(
+			mut parents: Query<(&mut InternalWrench, &mut ExternalForce, &mut ExternalTorque)>,
+		) {
+			for (mut internal_wrench, mut external_force, mut external_torque) in parents.iter_mut() {
+				internal_wrench.remove_from(&mut external_force, &mut external_torque);
 			}
 		}
 	}
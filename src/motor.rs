@@ -0,0 +1,247 @@
+//! Motorised actuators, which push their parent towards a target speed with a limited force.
+
+use serde::{Deserialize, Serialize};
+
+use crate::gimbal::gimballed_rotation;
+use crate::hierarchy::{world_lever_arm, RigidBodyAncestors};
+use crate::prelude::*;
+
+/// A motorised actuator, e.g. an elevator winch, a hover platform or a conveyor pusher,
+/// which drives the velocity of its parent at the child's position, along the child's axis, towards a target speed,
+/// but never pushes harder than its maximum force.
+///
+/// Every time [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs,
+/// the force needed to reach the target speed within the response time (or by the end of the physics steps
+/// that frame, which all apply the same force) is computed from the parent's
+/// [LinearVelocity], [AngularVelocity], [Mass] and [Inertia], clamped, and written into the child's
+/// [InternalFrame::ChildLocal] [InternalForce] (inserted automatically if missing).
+/// The [InternalForce]'s strength is left alone, so it still scales the force, e.g. to switch the motor off.
+///
+/// Only the speed along the axis is driven, so a motor pushing sideways on its parent still lets it slide along its axis
+/// under other forces.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d_parenting::prelude::*;
+///
+/// let lift = VelocityMotor::new(2.0, 5000.0).with_response_time(0.5);
+/// // a 100 kg platform at rest, ignoring gravity, needs 400 N to reach 2 m/s in half a second
+/// assert_eq!(lift.compute_force(0.0, 100.0, 0.0, 1.0 / 60.0), 400.0);
+/// // but a 2000 kg one is limited to 5000 N
+/// assert_eq!(lift.compute_force(0.0, 2000.0, 0.0, 1.0 / 60.0), 5000.0);
+/// ```
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct VelocityMotor {
+	/// The speed to reach along the axis, in meters per second
+	pub target_speed: f32,
+	/// The largest force the motor can push or pull with, in Newtons
+	pub max_force: f32,
+	/// The direction the motor drives, in the child's local space. Defaults to [Vec3::Y]
+	pub axis: Vec3,
+	/// How long the motor aims to take to reach its target speed, in seconds.
+	/// `0.0` (the default) aims to reach it within a single frame's physics steps
+	pub response_time: f32,
+	/// Whether to also push against the parent's [Gravity] along the axis, using its [GravityScale].
+	/// Without it a lifting motor settles slightly below its target speed
+	pub compensate_gravity: bool,
+	force: f32,
+}
+
+impl VelocityMotor {
+	/// Creates a [VelocityMotor] driving along its local Y axis as quickly as it can,
+	/// compensating for gravity
+	pub fn new(target_speed: f32, max_force: f32) -> Self {
+		Self {
+			target_speed,
+			max_force: max_force.max(0.0),
+			axis: Vec3::Y,
+			response_time: 0.0,
+			compensate_gravity: true,
+			force: 0.0,
+		}
+	}
+
+	pub fn with_axis(mut self, axis: Vec3) -> Self {
+		self.axis = axis;
+		self
+	}
+
+	pub fn with_response_time(mut self, response_time: f32) -> Self {
+		self.response_time = response_time.max(0.0);
+		self
+	}
+
+	pub fn with_gravity_compensation(mut self, compensate_gravity: bool) -> Self {
+		self.compensate_gravity = compensate_gravity;
+		self
+	}
+
+	/// The force along the axis applied during the last frame's physics steps, in Newtons
+	pub fn get_force(&self) -> f32 {
+		self.force
+	}
+
+	/// Whether the motor pushed with its maximum force during the last frame's physics steps,
+	/// so may not be reaching its target speed
+	pub fn is_saturated(&self) -> bool {
+		self.force != 0.0 && self.force.abs() >= self.max_force
+	}
+
+	/// The force along the axis needed to change `speed` into the target speed within the response time
+	/// (or `delta_seconds`, if longer), clamped to the maximum force.
+	///
+	/// `effective_mass` is the mass the parent resists being pushed along the axis with at the child's position,
+	/// and `acceleration` is the acceleration along the axis the parent is already undergoing, e.g. from gravity.
+	pub fn compute_force(
+		&self,
+		speed: f32,
+		effective_mass: f32,
+		acceleration: f32,
+		delta_seconds: f32,
+	) -> f32 {
+		let response_time = self.response_time.max(delta_seconds);
+		if response_time <= 0.0 || effective_mass <= 0.0 {
+			return 0.0;
+		}
+		let needed_acceleration = (self.target_speed - speed) / response_time - acceleration;
+		if needed_acceleration == 0.0 {
+			// avoids multiplying zero by the infinite effective mass of a static body
+			return 0.0;
+		}
+		let force = needed_acceleration * effective_mass;
+		if force.is_nan() {
+			0.0
+		} else {
+			force.clamp(-self.max_force, self.max_force)
+		}
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts an [InternalForce] on every [VelocityMotor] that doesn't have one yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_velocity_motors(
+		mut commands: Commands,
+		motors: Query<Entity, (With<VelocityMotor>, Without<InternalForce>)>,
+	) {
+		for entity in motors.iter() {
+			commands
+				.entity(entity)
+				.insert(InternalForce::new_local(Vec3::ZERO));
+		}
+	}
+
+	/// Writes the force every [VelocityMotor] needs to reach its target speed by the end of this frame's
+	/// physics steps (or within its response time) into its [InternalForce].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn update_velocity_motors(
+		mut motors: Query<
+			(
+				Entity,
+				&mut VelocityMotor,
+				Option<&Gimbal>,
+				&mut InternalForce,
+			),
+			Without<RigidBody>,
+		>,
+		parents: Query<
+			(
+				&LinearVelocity,
+				&AngularVelocity,
+				&InverseMass,
+				&InverseInertia,
+				&CenterOfMass,
+				&Rotation,
+				&GlobalTransform,
+				Option<&GravityScale>,
+			),
+			With<RigidBody>,
+		>,
+		ancestors: RigidBodyAncestors,
+		gravity: Option<Res<Gravity>>,
+		time: Res<Time<Physics>>,
+		real_time: Res<Time<Real>>,
+	) {
+		if time.is_paused() {
+			return;
+		}
+		let delta_seconds = simulated_frame_seconds(&time, &real_time);
+		let gravity = gravity.map_or(Vec3::ZERO, |gravity| gravity.0);
+		for (child, mut motor, gimbal, mut internal_force) in motors.iter_mut() {
+			let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+				// reported by `diagnose`
+				continue;
+			};
+			let Ok((
+				linear_velocity,
+				angular_velocity,
+				inverse_mass,
+				inverse_inertia,
+				center_of_mass,
+				parent_rotation,
+				parent_global_transform,
+				gravity_scale,
+			)) = parents.get(rigid_body)
+			else {
+				continue;
+			};
+
+			let axis = motor.axis.normalize_or_zero();
+			let world_axis = (parent_rotation.0
+				* gimballed_rotation(child_relative_transform.rotation, gimbal))
+			.mul_vec3(axis);
+			let lever_arm = world_lever_arm(
+				child_relative_transform.translation,
+				parent_global_transform.compute_transform().scale,
+				parent_rotation.0,
+				center_of_mass.0,
+			);
+
+			let speed = (linear_velocity.0 + angular_velocity.0.cross(lever_arm)).dot(world_axis);
+			// how much the speed at the child changes per unit of impulse along the axis
+			let angular_response = lever_arm.cross(world_axis);
+			let inverse_effective_mass = inverse_mass.0
+				+ angular_response.dot(inverse_inertia.rotated(parent_rotation).0 * angular_response);
+			let effective_mass = if inverse_effective_mass > 0.0 {
+				inverse_effective_mass.recip()
+			} else {
+				f32::INFINITY
+			};
+			let acceleration = if motor.compensate_gravity {
+				gravity.dot(world_axis) * gravity_scale.map_or(1.0, |scale| scale.0)
+			} else {
+				0.0
+			};
+
+			let force = motor.compute_force(speed, effective_mass, acceleration, delta_seconds);
+			if motor.force != force {
+				motor.force = force;
+			}
+			internal_force.force = axis * force;
+			internal_force.frame = InternalFrame::ChildLocal;
+		}
+	}
+}
+
+/// The simulated time covered by the physics steps about to run this frame, each applying the same forces.
+/// Mirrors how bevy_xpbd consumes its accumulated overstep in [TimestepMode::Fixed]
+fn simulated_frame_seconds(time: &Time<Physics>, real_time: &Time<Real>) -> f32 {
+	let relative_speed = time.relative_speed_f64();
+	let simulated = match time.timestep_mode() {
+		TimestepMode::Fixed {
+			delta,
+			overstep,
+			max_delta_overstep,
+		} => {
+			let overstep = overstep + real_time.delta().min(max_delta_overstep);
+			let steps = (overstep.as_secs_f64() / delta.as_secs_f64()) as u32;
+			delta.mul_f64(relative_speed) * steps
+		}
+		TimestepMode::FixedOnce { delta } => delta.mul_f64(relative_speed),
+		TimestepMode::Variable { max_delta } => {
+			real_time.delta().mul_f64(relative_speed).min(max_delta)
+		}
+	};
+	simulated.as_secs_f32()
+}
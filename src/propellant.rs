@@ -0,0 +1,211 @@
+//! Propellant tanks that feed [Thruster]s and add their mass to their [RigidBody] ancestor.
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy_xpbd_3d::components::MassPropertiesQuery;
+use serde::{Deserialize, Serialize};
+
+use crate::hierarchy::RigidBodyAncestors;
+use crate::prelude::*;
+
+/// Standard gravity, used to convert a specific impulse (in seconds) into an exhaust velocity
+pub const STANDARD_GRAVITY: f32 = 9.80665;
+
+/// A tank of propellant, drained by every [Thruster] with a [PropellantFeed] pointing to it.
+///
+/// The mass of the propellant is added to the [Mass] of the tank's [RigidBody] ancestor,
+/// at the position of the tank, so the parent's [CenterOfMass] shifts as it is burnt.
+/// The tank's own (dry) mass should be given using a [Collider] as usual.
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct PropellantTank {
+	/// The maximum mass of propellant the tank can hold, in kilograms
+	capacity: f32,
+	/// The mass of propellant left, in kilograms
+	propellant: f32,
+}
+
+impl PropellantTank {
+	/// Creates a full tank holding `capacity` kilograms of propellant
+	pub fn new(capacity: f32) -> Self {
+		let capacity = capacity.max(0.0);
+		Self {
+			capacity,
+			propellant: capacity,
+		}
+	}
+
+	pub fn get_capacity(&self) -> f32 {
+		self.capacity
+	}
+
+	pub fn get_propellant(&self) -> f32 {
+		self.propellant
+	}
+
+	/// Clamped between `0.0` and the capacity
+	pub fn set_propellant(&mut self, propellant: f32) {
+		self.propellant = propellant.clamp(0.0, self.capacity);
+	}
+
+	pub fn with_propellant(mut self, propellant: f32) -> Self {
+		self.set_propellant(propellant);
+		self
+	}
+
+	/// Between `0.0` (empty) and `1.0` (full)
+	pub fn get_fill_fraction(&self) -> f32 {
+		if self.capacity > 0.0 {
+			self.propellant / self.capacity
+		} else {
+			0.0
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.propellant <= 0.0
+	}
+
+	pub fn refill(&mut self) {
+		self.propellant = self.capacity;
+	}
+
+	/// Removes up to `mass` kilograms of propellant, returning how much was actually removed
+	pub fn drain(&mut self, mass: f32) -> f32 {
+		let drained = mass.clamp(0.0, self.propellant);
+		self.propellant -= drained;
+		drained
+	}
+}
+
+/// Links a [Thruster] to the [PropellantTank] it burns.
+///
+/// The thruster consumes propellant at `thrust / (specific_impulse * STANDARD_GRAVITY)` kilograms per second,
+/// and stops pushing once the tank is empty (or doesn't exist).
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct PropellantFeed {
+	/// The entity with the [PropellantTank]
+	pub tank: Entity,
+	/// The specific impulse of the thruster, in seconds
+	pub specific_impulse: f32,
+}
+
+impl PropellantFeed {
+	pub fn new(tank: Entity, specific_impulse: f32) -> Self {
+		Self {
+			tank,
+			specific_impulse,
+		}
+	}
+
+	/// The propellant consumed per second while producing `thrust` Newtons, in kilograms per second.
+	/// A non-positive specific impulse consumes nothing
+	pub fn compute_mass_flow(&self, thrust: f32) -> f32 {
+		if self.specific_impulse > 0.0 {
+			thrust.abs() / (self.specific_impulse * STANDARD_GRAVITY)
+		} else {
+			0.0
+		}
+	}
+}
+
+/// The propellant mass last added to a [RigidBody] by a [PropellantTank]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TankMass {
+	parent: Entity,
+	mass: f32,
+	/// In the parent's local space, like [CenterOfMass]
+	center_of_mass: Vec3,
+}
+
+impl TankMass {
+	fn mass_properties(&self) -> ColliderMassProperties {
+		ColliderMassProperties {
+			mass: Mass(self.mass),
+			inverse_mass: InverseMass(1.0 / self.mass),
+			// a point mass, the parallel axis theorem is applied when it is added to the parent
+			inertia: Inertia::ZERO,
+			inverse_inertia: InverseInertia::ZERO,
+			center_of_mass: CenterOfMass(self.center_of_mass),
+		}
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Drains the [PropellantTank] of every [Thruster] with a [PropellantFeed], according to its current thrust
+	/// (as applied by its [ActuatorDynamics], if any).
+	/// This is automatically scheduled in the [PhysicsSchedule](bevy_xpbd_3d::PhysicsSchedule),
+	/// so that exactly the propellant used by each physics step is consumed
+	pub(crate) fn consume_propellant(
+		thrusters: Query<(&InternalForce, &PropellantFeed, Option<&ActuatorDynamics>), With<Thruster>>,
+		mut tanks: Query<&mut PropellantTank>,
+		time: Res<Time>,
+	) {
+		for (internal_force, feed, dynamics) in thrusters.iter() {
+			let thrust = ActuatorDynamics::apply_to_force(dynamics, internal_force).length();
+			let consumed = feed.compute_mass_flow(thrust) * time.delta_seconds();
+			if consumed <= 0.0 {
+				continue;
+			}
+			if let Ok(mut tank) = tanks.get_mut(feed.tank) {
+				tank.drain(consumed);
+			}
+		}
+	}
+
+	/// Adds the mass of every [PropellantTank]'s propellant to its [RigidBody] ancestor,
+	/// removing the mass that was previously added.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn update_propellant_mass(
+		tanks: Query<(Entity, &PropellantTank), Without<RigidBody>>,
+		mut removed_tanks: RemovedComponents<PropellantTank>,
+		mut parents: Query<(MassPropertiesQuery, &GlobalTransform), With<RigidBody>>,
+		ancestors: RigidBodyAncestors,
+		mut added: Local<EntityHashMap<TankMass>>,
+	) {
+		for tank in removed_tanks.read() {
+			if let Some(previous) = added.remove(&tank) {
+				if let Ok((mut mass_properties, _)) = parents.get_mut(previous.parent) {
+					mass_properties -= previous.mass_properties();
+				}
+			}
+		}
+
+		for (tank_entity, tank) in tanks.iter() {
+			let current = ancestors
+				.find(tank_entity)
+				.and_then(|(parent, child_relative_transform)| {
+					let (_, parent_global_transform) = parents.get(parent).ok()?;
+					// bevy_xpbd doesn't track scale, so it is taken from the `GlobalTransform`
+					let scale = parent_global_transform.compute_transform().scale;
+					Some(TankMass {
+						parent,
+						mass: tank.get_propellant(),
+						center_of_mass: child_relative_transform.translation * scale,
+					})
+				})
+				.filter(|current| current.mass > 0.0);
+
+			let previous = added.get(&tank_entity).copied();
+			if previous == current {
+				continue;
+			}
+			if let Some(previous) = previous {
+				if let Ok((mut mass_properties, _)) = parents.get_mut(previous.parent) {
+					mass_properties -= previous.mass_properties();
+				}
+			}
+			match current {
+				Some(current) => {
+					if let Ok((mut mass_properties, _)) = parents.get_mut(current.parent) {
+						mass_properties += current.mass_properties();
+					}
+					added.insert(tank_entity, current);
+				}
+				None => {
+					added.remove(&tank_entity);
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,211 @@
+//! Propellers and rotors, which produce thrust and a reaction torque.
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Which way a [Rotor] spins, when looking down its axis from the side it pushes towards
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SpinDirection {
+	/// The rotor's angular momentum points along its axis, so the parent is twisted the opposite way
+	#[default]
+	CounterClockwise,
+	/// The rotor's angular momentum points against its axis, so the parent is twisted along it
+	Clockwise,
+}
+
+impl SpinDirection {
+	/// `1.0` for [SpinDirection::CounterClockwise] and `-1.0` for [SpinDirection::Clockwise]
+	pub fn sign(&self) -> f32 {
+		match self {
+			SpinDirection::CounterClockwise => 1.0,
+			SpinDirection::Clockwise => -1.0,
+		}
+	}
+
+	pub fn reversed(&self) -> Self {
+		match self {
+			SpinDirection::CounterClockwise => SpinDirection::Clockwise,
+			SpinDirection::Clockwise => SpinDirection::CounterClockwise,
+		}
+	}
+}
+
+/// A propeller or helicopter rotor, which spins up towards a commanded RPM and pushes along its axis,
+/// twisting its parent the opposite way to its spin.
+///
+/// Both thrust and reaction torque grow with the square of the RPM.
+/// Writes [InternalFrame::ChildLocal] [InternalForce] and [InternalTorque]s (inserted automatically if missing)
+/// every time [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d_parenting::prelude::*;
+///
+/// let mut rotor = Rotor::new(20.0, 0.5, 6000.0).with_spool_time(0.0);
+/// rotor.set_command_rpm(3000.0);
+/// rotor.snap_to_command();
+/// // half the RPM, a quarter of the thrust
+/// assert_eq!(rotor.compute_thrust(), Vec3::Y * 5.0);
+/// ```
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct Rotor {
+	/// The thrust at the maximum RPM, in Newtons
+	pub max_thrust: f32,
+	/// The reaction torque at the maximum RPM, in Newton meters
+	pub max_torque: f32,
+	max_rpm: f32,
+	/// The time constant of the RPM's first order response to the command, in seconds.
+	/// `0.0` responds instantly
+	pub spool_time: f32,
+	/// The direction the rotor pushes in, in the child's local space. Defaults to [Vec3::Y]
+	pub axis: Vec3,
+	pub spin: SpinDirection,
+	command_rpm: f32,
+	rpm: f32,
+}
+
+impl Rotor {
+	/// Creates a stationary counter-clockwise [Rotor] pushing along its local Y axis,
+	/// with a spool time of a quarter of a second
+	pub fn new(max_thrust: f32, max_torque: f32, max_rpm: f32) -> Self {
+		Self {
+			max_thrust,
+			max_torque,
+			max_rpm: max_rpm.max(0.0),
+			spool_time: 0.25,
+			axis: Vec3::Y,
+			spin: SpinDirection::CounterClockwise,
+			command_rpm: 0.0,
+			rpm: 0.0,
+		}
+	}
+
+	pub fn with_axis(mut self, axis: Vec3) -> Self {
+		self.axis = axis;
+		self
+	}
+
+	pub fn with_spin(mut self, spin: SpinDirection) -> Self {
+		self.spin = spin;
+		self
+	}
+
+	pub fn with_spool_time(mut self, spool_time: f32) -> Self {
+		self.spool_time = spool_time.max(0.0);
+		self
+	}
+
+	pub fn get_max_rpm(&self) -> f32 {
+		self.max_rpm
+	}
+
+	pub fn get_command_rpm(&self) -> f32 {
+		self.command_rpm
+	}
+
+	/// Clamped between `0.0` and the maximum RPM
+	pub fn set_command_rpm(&mut self, rpm: f32) {
+		self.command_rpm = if rpm.is_nan() {
+			0.0
+		} else {
+			rpm.clamp(0.0, self.max_rpm)
+		};
+	}
+
+	pub fn with_command_rpm(mut self, rpm: f32) -> Self {
+		self.set_command_rpm(rpm);
+		self
+	}
+
+	/// The current RPM, which lags behind the command
+	pub fn get_rpm(&self) -> f32 {
+		self.rpm
+	}
+
+	/// Immediately spins at the commanded RPM, ignoring the spool time
+	pub fn snap_to_command(&mut self) {
+		self.rpm = self.command_rpm;
+	}
+
+	/// The current RPM as a fraction of the maximum, squared
+	fn load(&self) -> f32 {
+		if self.max_rpm > 0.0 {
+			(self.rpm / self.max_rpm).powi(2)
+		} else {
+			0.0
+		}
+	}
+
+	/// The force at the current RPM, in the child's local space
+	pub fn compute_thrust(&self) -> Vec3 {
+		self.axis.normalize_or_zero() * self.max_thrust * self.load()
+	}
+
+	/// The torque on the parent at the current RPM, in the child's local space
+	pub fn compute_reaction_torque(&self) -> Vec3 {
+		-self.axis.normalize_or_zero() * self.spin.sign() * self.max_torque * self.load()
+	}
+
+	/// Moves the RPM towards the command over `delta_seconds`
+	pub(crate) fn spool(&mut self, delta_seconds: f32) {
+		if self.spool_time <= 0.0 {
+			self.rpm = self.command_rpm;
+		} else {
+			let blend = 1.0 - (-delta_seconds / self.spool_time).exp();
+			self.rpm += (self.command_rpm - self.rpm) * blend;
+		}
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts an [InternalForce] and [InternalTorque] on every [Rotor] that doesn't have them yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_rotors(
+		mut commands: Commands,
+		rotors: Query<
+			(Entity, Has<InternalForce>, Has<InternalTorque>),
+			(
+				With<Rotor>,
+				Or<(Without<InternalForce>, Without<InternalTorque>)>,
+			),
+		>,
+	) {
+		for (entity, has_force, has_torque) in rotors.iter() {
+			let mut entity_commands = commands.entity(entity);
+			if !has_force {
+				entity_commands.insert(InternalForce::new_local(Vec3::ZERO));
+			}
+			if !has_torque {
+				entity_commands.insert(InternalTorque::new_local(Vec3::ZERO));
+			}
+		}
+	}
+
+	/// Writes the thrust and reaction torque of every [Rotor] at its current RPM
+	/// into its [InternalForce] and [InternalTorque].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn update_rotors(
+		mut rotors: Query<(&mut Rotor, &mut InternalForce, &mut InternalTorque)>,
+	) {
+		for (mut rotor, mut internal_force, mut internal_torque) in rotors.iter_mut() {
+			// responding instantly doesn't depend on the physics clock
+			if rotor.spool_time <= 0.0 && rotor.rpm != rotor.command_rpm {
+				rotor.snap_to_command();
+			}
+			*internal_force = InternalForce::new_local(rotor.compute_thrust());
+			*internal_torque = InternalTorque::new_local(rotor.compute_reaction_torque());
+		}
+	}
+
+	/// Spools every [Rotor] towards its command over a single physics step.
+	/// This is automatically scheduled in the [PhysicsSchedule](bevy_xpbd_3d::PhysicsSchedule)
+	pub(crate) fn spool_rotors(mut rotors: Query<&mut Rotor>, time: Res<Time>) {
+		for mut rotor in rotors.iter_mut() {
+			if rotor.rpm != rotor.command_rpm {
+				rotor.spool(time.delta_seconds());
+			}
+		}
+	}
+}
@@ -0,0 +1,36 @@
+//! Reporting the components that are automatically inserted on parents.
+
+use crate::prelude::*;
+
+/// A component the [ParentingPlugin](crate::ParentingPlugin) can insert on a [RigidBody] parent
+/// that its children need in order to exert forces on it.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParentComponent {
+	/// A non-persistent [ExternalForce], needed for [InternalForce](crate::InternalForce) children
+	ExternalForce,
+	/// A non-persistent [ExternalTorque], needed for [InternalForce](crate::InternalForce)
+	/// and [InternalTorque](crate::InternalTorque) children
+	ExternalTorque,
+	/// A non-persistent [ExternalImpulse], needed for [InternalImpulse](crate::InternalImpulse) children
+	ExternalImpulse,
+	/// An [InternalWrench](crate::InternalWrench), needed for [InternalForce](crate::InternalForce)
+	/// and [InternalTorque](crate::InternalTorque) children
+	InternalWrench,
+	/// An [InternalWrenchLedger](crate::InternalWrenchLedger), inserted alongside the
+	/// [InternalWrench](crate::InternalWrench)
+	InternalWrenchLedger,
+	/// A [ThrustAllocator](crate::ThrustAllocator), needed for controllers like
+	/// [AttitudeHold](crate::AttitudeHold)
+	ThrustAllocator,
+}
+
+/// Sent whenever the [ParentingPlugin](crate::ParentingPlugin) inserts components that a
+/// [RigidBody] parent was missing, so that forgetting them is no longer a source of bugs.
+/// Each insertion is also logged using `tracing`.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ParentComponentsInserted {
+	/// The [RigidBody] that the components were inserted on
+	pub parent: Entity,
+	/// The components that were inserted
+	pub components: Vec<ParentComponent>,
+}
@@ -0,0 +1,152 @@
+//! Rated thrusters with a throttle, built on top of [InternalForce].
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// A rocket engine, jet or any other thruster, which pushes its [RigidBody] ancestor
+/// by writing an [InternalForce] in its own [InternalFrame::ChildLocal] frame,
+/// whose force is the thrust at full throttle and whose strength is the throttle.
+///
+/// The [InternalForce] is inserted automatically if missing,
+/// and overwritten every time [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs,
+/// so systems changing the throttle should be scheduled before it.
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d_parenting::prelude::*;
+///
+/// // 100 N of thrust upwards (the exhaust leaves downwards) at half throttle
+/// let thruster = Thruster::new(100.0)
+///   .with_exhaust_direction(Vec3::NEG_Y)
+///   .with_throttle(0.5);
+/// assert_eq!(thruster.compute_thrust(), Vec3::Y * 50.0);
+/// ```
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct Thruster {
+	/// The thrust at full throttle, in Newtons. Never negative
+	max_thrust: f32,
+	/// Between `0.0` and `1.0`
+	throttle: f32,
+	/// The normalized direction the exhaust leaves the nozzle in, in the child's local space.
+	/// The thrust points the opposite way
+	exhaust_direction: Vec3,
+}
+
+impl Default for Thruster {
+	fn default() -> Self {
+		Self::new(0.0)
+	}
+}
+
+impl Thruster {
+	/// Creates a [Thruster] rated for `max_thrust` Newtons,
+	/// with its exhaust pointing down ([Vec3::NEG_Y]) so that it pushes up, at zero throttle
+	pub fn new(max_thrust: f32) -> Self {
+		Self {
+			max_thrust: max_thrust.max(0.0),
+			throttle: 0.0,
+			exhaust_direction: Vec3::NEG_Y,
+		}
+	}
+
+	pub fn get_max_thrust(&self) -> f32 {
+		self.max_thrust
+	}
+
+	/// Negative values are treated as `0.0`
+	pub fn set_max_thrust(&mut self, max_thrust: f32) {
+		self.max_thrust = max_thrust.max(0.0);
+	}
+
+	pub fn with_max_thrust(mut self, max_thrust: f32) -> Self {
+		self.set_max_thrust(max_thrust);
+		self
+	}
+
+	pub fn get_throttle(&self) -> f32 {
+		self.throttle
+	}
+
+	/// Clamped between `0.0` and `1.0`, `NaN` is treated as `0.0`
+	pub fn set_throttle(&mut self, throttle: f32) {
+		self.throttle = if throttle.is_nan() {
+			0.0
+		} else {
+			throttle.clamp(0.0, 1.0)
+		};
+	}
+
+	pub fn with_throttle(mut self, throttle: f32) -> Self {
+		self.set_throttle(throttle);
+		self
+	}
+
+	pub fn get_exhaust_direction(&self) -> Vec3 {
+		self.exhaust_direction
+	}
+
+	/// Sets the direction the exhaust leaves the nozzle in, in the child's local space.
+	/// Normalized, and a zero direction means the thruster can't push at all
+	pub fn set_exhaust_direction(&mut self, exhaust_direction: Vec3) {
+		self.exhaust_direction = exhaust_direction.normalize_or_zero();
+	}
+
+	pub fn with_exhaust_direction(mut self, exhaust_direction: Vec3) -> Self {
+		self.set_exhaust_direction(exhaust_direction);
+		self
+	}
+
+	/// The normalized direction the thruster pushes in, in the child's local space
+	pub fn get_thrust_direction(&self) -> Vec3 {
+		-self.exhaust_direction
+	}
+
+	/// Computes the force the thruster exerts at its current throttle, in the child's local space
+	pub fn compute_thrust(&self) -> Vec3 {
+		self.compute_max_thrust() * self.throttle
+	}
+
+	/// Computes the force the thruster exerts at full throttle, in the child's local space
+	pub fn compute_max_thrust(&self) -> Vec3 {
+		self.get_thrust_direction() * self.max_thrust
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts an [InternalForce] on every [Thruster] that doesn't have one yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_thrusters(
+		mut commands: Commands,
+		thrusters: Query<(Entity, &Thruster), Without<InternalForce>>,
+	) {
+		for (entity, thruster) in thrusters.iter() {
+			commands.entity(entity).insert(
+				InternalForce::new_local(thruster.compute_max_thrust())
+					.with_strength(thruster.get_throttle()),
+			);
+		}
+	}
+
+	/// Writes the thrust of every [Thruster] into its [InternalForce].
+	/// Thrusters with a [PropellantFeed](crate::PropellantFeed) whose tank is empty (or missing) are written with a strength of `0.0`,
+	/// so they don't push and any [ActuatorDynamics](crate::ActuatorDynamics) shuts down until the tank is refilled.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn update_thrusters(
+		mut thrusters: Query<(&Thruster, Option<&PropellantFeed>, &mut InternalForce)>,
+		tanks: Query<&PropellantTank>,
+	) {
+		for (thruster, feed, mut internal_force) in thrusters.iter_mut() {
+			let has_propellant = feed.map_or(true, |feed| {
+				tanks.get(feed.tank).is_ok_and(|tank| !tank.is_empty())
+			});
+			let throttle = if has_propellant {
+				thruster.get_throttle()
+			} else {
+				0.0
+			};
+			*internal_force =
+				InternalForce::new_local(thruster.compute_max_thrust()).with_strength(throttle);
+		}
+	}
+}
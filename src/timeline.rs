@@ -0,0 +1,313 @@
+//! Keyframed force timelines, loaded from RON assets and played back on [InternalForce] children.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::utils::BoxedFuture;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// How a [ForceKeyframe] blends into the next one
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Interpolation {
+	/// Holds this keyframe's values until the next keyframe
+	Step,
+	/// Blends linearly into the next keyframe
+	#[default]
+	Linear,
+	/// Blends into the next keyframe, easing in and out of both
+	Smooth,
+}
+
+impl Interpolation {
+	/// Maps the `fraction` of the way between two keyframes to how much of the next keyframe is used
+	pub fn ease(&self, fraction: f32) -> f32 {
+		let fraction = fraction.clamp(0.0, 1.0);
+		match self {
+			Interpolation::Step => 0.0,
+			Interpolation::Linear => fraction,
+			Interpolation::Smooth => fraction * fraction * (3.0 - 2.0 * fraction),
+		}
+	}
+}
+
+/// The strength (and optionally the direction) of an [InternalForce] at a point in a [ForceTimeline]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ForceKeyframe {
+	/// In seconds since the start of the timeline
+	pub time: f32,
+	/// Written into [InternalForce::strength]
+	pub strength: f32,
+	/// Written into [InternalForce::force], in the [InternalFrame] of the child's [InternalForce].
+	/// [None] keeps the direction of the previous keyframe, or leaves the force as it is if no previous keyframe has one
+	#[serde(default)]
+	pub direction: Option<Vec3>,
+	/// How this keyframe blends into the next one
+	#[serde(default)]
+	pub interpolation: Interpolation,
+}
+
+/// The values of a [ForceTimeline] at a point in time, see [ForceTimeline::sample]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForceSample {
+	pub strength: f32,
+	pub direction: Option<Vec3>,
+}
+
+/// A sequence of [ForceKeyframe]s, e.g. a launch sequence or an engine test pattern,
+/// which a [ForceTimelinePlayer] plays back on an [InternalForce] child.
+///
+/// Loaded from `.timeline.ron` files when the opt-in `timeline` feature is enabled,
+/// and the app has an [AssetPlugin]:
+/// ```ron
+/// (
+///   keyframes: [
+///     (time: 0.0, strength: 0.0, direction: Some((0.0, 1.0, 0.0))),
+///     (time: 2.0, strength: 1.0, interpolation: Smooth),
+///     (time: 5.0, strength: 0.5, interpolation: Step),
+///   ],
+///   looping: false,
+/// )
+/// ```
+#[derive(Asset, Reflect, Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ForceTimeline {
+	/// Sorted by [ForceKeyframe::time]
+	pub keyframes: Vec<ForceKeyframe>,
+	/// Whether the timeline starts again from `0.0` once it passes its last keyframe
+	#[serde(default)]
+	pub looping: bool,
+}
+
+impl ForceTimeline {
+	/// Creates a timeline from keyframes, sorting them by time
+	pub fn new(keyframes: impl IntoIterator<Item = ForceKeyframe>, looping: bool) -> Self {
+		let mut keyframes: Vec<ForceKeyframe> = keyframes.into_iter().collect();
+		keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+		Self { keyframes, looping }
+	}
+
+	/// Parses a timeline from RON, as the asset loader does
+	pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+		ron::de::from_str(ron)
+	}
+
+	/// The time of the last keyframe, in seconds
+	pub fn duration(&self) -> f32 {
+		self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+	}
+
+	/// Whether a (non-looping) timeline has passed its last keyframe after `elapsed` seconds
+	pub fn is_finished(&self, elapsed: f32) -> bool {
+		!self.looping && elapsed >= self.duration()
+	}
+
+	/// The values of the timeline `elapsed` seconds after it started,
+	/// or [None] if it has no keyframes.
+	/// Before the first keyframe and after the last (unless looping), the nearest keyframe is held
+	pub fn sample(&self, elapsed: f32) -> Option<ForceSample> {
+		let duration = self.duration();
+		let time = if self.looping && duration > 0.0 {
+			elapsed.rem_euclid(duration)
+		} else {
+			elapsed
+		};
+
+		let next_index = self
+			.keyframes
+			.partition_point(|keyframe| keyframe.time <= time);
+		if next_index == 0 {
+			return self.keyframes.first().map(|keyframe| ForceSample {
+				strength: keyframe.strength,
+				direction: keyframe.direction,
+			});
+		}
+		let previous = &self.keyframes[next_index - 1];
+		let held_direction = self.keyframes[..next_index]
+			.iter()
+			.rev()
+			.find_map(|keyframe| keyframe.direction);
+		let Some(next) = self.keyframes.get(next_index) else {
+			return Some(ForceSample {
+				strength: previous.strength,
+				direction: held_direction,
+			});
+		};
+
+		let fraction = (time - previous.time) / (next.time - previous.time);
+		let blend = previous.interpolation.ease(fraction);
+		Some(ForceSample {
+			strength: previous.strength + (next.strength - previous.strength) * blend,
+			direction: match (previous.direction, next.direction) {
+				(Some(from), Some(to)) => Some(from.lerp(to, blend)),
+				_ => held_direction,
+			},
+		})
+	}
+}
+
+/// Plays a [ForceTimeline] back on this child's [InternalForce] (inserted automatically if missing),
+/// by overwriting its strength (and direction) every time
+/// [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs.
+///
+/// Playback starts once the timeline has loaded, and advances after every physics step, so stops while physics is paused.
+/// Children whose [InternalForce] is already written by the plugin, like [Thruster]s, shouldn't also have a player.
+#[derive(Reflect, Component, Debug, Clone)]
+#[reflect(Component)]
+pub struct ForceTimelinePlayer {
+	pub timeline: Handle<ForceTimeline>,
+	/// How fast the timeline is played, where `1.0` is the speed of the physics clock
+	pub speed: f32,
+	pub paused: bool,
+	elapsed: f32,
+}
+
+impl ForceTimelinePlayer {
+	pub fn new(timeline: Handle<ForceTimeline>) -> Self {
+		Self {
+			timeline,
+			speed: 1.0,
+			paused: false,
+			elapsed: 0.0,
+		}
+	}
+
+	pub fn with_speed(mut self, speed: f32) -> Self {
+		self.speed = speed;
+		self
+	}
+
+	/// How far into the timeline the player is, in seconds
+	pub fn get_elapsed(&self) -> f32 {
+		self.elapsed
+	}
+
+	/// Jumps to `elapsed` seconds into the timeline
+	pub fn seek(&mut self, elapsed: f32) {
+		self.elapsed = elapsed.max(0.0);
+	}
+
+	pub fn restart(&mut self) {
+		self.seek(0.0);
+	}
+}
+
+/// Loads [ForceTimeline]s from `.timeline.ron` files
+#[derive(Default)]
+pub struct ForceTimelineLoader;
+
+/// Why a [ForceTimeline] couldn't be loaded
+#[derive(Debug)]
+pub enum ForceTimelineLoaderError {
+	Io(std::io::Error),
+	Ron(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for ForceTimelineLoaderError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ForceTimelineLoaderError::Io(error) => write!(f, "Couldn't read force timeline: {error}"),
+			ForceTimelineLoaderError::Ron(error) => write!(f, "Couldn't parse force timeline: {error}"),
+		}
+	}
+}
+
+impl std::error::Error for ForceTimelineLoaderError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			ForceTimelineLoaderError::Io(error) => Some(error),
+			ForceTimelineLoaderError::Ron(error) => Some(error),
+		}
+	}
+}
+
+impl From<std::io::Error> for ForceTimelineLoaderError {
+	fn from(error: std::io::Error) -> Self {
+		Self::Io(error)
+	}
+}
+
+impl From<ron::error::SpannedError> for ForceTimelineLoaderError {
+	fn from(error: ron::error::SpannedError) -> Self {
+		Self::Ron(error)
+	}
+}
+
+impl AssetLoader for ForceTimelineLoader {
+	type Asset = ForceTimeline;
+	type Settings = ();
+	type Error = ForceTimelineLoaderError;
+
+	fn load<'a>(
+		&'a self,
+		reader: &'a mut Reader,
+		_settings: &'a Self::Settings,
+		_load_context: &'a mut LoadContext,
+	) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+		Box::pin(async move {
+			let mut bytes = Vec::new();
+			reader.read_to_end(&mut bytes).await?;
+			let mut timeline: ForceTimeline = ron::de::from_bytes(&bytes)?;
+			timeline.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+			Ok(timeline)
+		})
+	}
+
+	fn extensions(&self) -> &[&str] {
+		&["timeline.ron"]
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts an [InternalForce] on every [ForceTimelinePlayer] that doesn't have one yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_force_timelines(
+		mut commands: Commands,
+		players: Query<Entity, (With<ForceTimelinePlayer>, Without<InternalForce>)>,
+	) {
+		for entity in players.iter() {
+			commands.entity(entity).insert(InternalForce::default());
+		}
+	}
+
+	/// Writes the timeline of every [ForceTimelinePlayer] that has loaded into its [InternalForce],
+	/// sampled at how far into it the player is.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn play_force_timelines(
+		mut players: Query<(&ForceTimelinePlayer, &mut InternalForce)>,
+		timelines: Option<Res<Assets<ForceTimeline>>>,
+	) {
+		let Some(timelines) = timelines else {
+			return;
+		};
+		for (player, mut internal_force) in players.iter_mut() {
+			let Some(sample) = timelines
+				.get(&player.timeline)
+				.and_then(|timeline| timeline.sample(player.elapsed))
+			else {
+				continue;
+			};
+			internal_force.set_strength(sample.strength);
+			if let Some(direction) = sample.direction {
+				internal_force.force = direction;
+			}
+		}
+	}
+
+	/// Advances every [ForceTimelinePlayer] whose timeline has loaded over a single physics step.
+	/// This is automatically scheduled in the [PhysicsSchedule](bevy_xpbd_3d::PhysicsSchedule)
+	pub(crate) fn advance_force_timelines(
+		mut players: Query<&mut ForceTimelinePlayer>,
+		timelines: Option<Res<Assets<ForceTimeline>>>,
+		time: Res<Time>,
+	) {
+		let Some(timelines) = timelines else {
+			return;
+		};
+		for mut player in players.iter_mut() {
+			if player.paused || !timelines.contains(&player.timeline) {
+				continue;
+			}
+			player.elapsed = (player.elapsed + time.delta_seconds() * player.speed).max(0.0);
+		}
+	}
+}
@@ -0,0 +1,283 @@
+//! Raycast wheels, which hold their parent up on a suspension and grip the ground with friction.
+
+use bevy_xpbd_3d::plugins::spatial_query::{SpatialQuery, SpatialQueryFilter};
+use serde::{Deserialize, Serialize};
+
+use crate::hierarchy::{world_lever_arm, world_point, RigidBodyAncestors};
+use crate::prelude::*;
+
+/// Where a [RaycastWheel] touches the ground
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct WheelContact {
+	/// The collider that the wheel is resting on
+	pub entity: Entity,
+	/// The contact point, in world space
+	pub point: Vec3,
+	/// The ground's normal at [WheelContact::point], in world space
+	pub normal: Vec3,
+	/// How far the suspension is compressed from its rest length, in meters
+	pub compression: f32,
+	/// The force holding the parent up, in Newtons
+	pub load: f32,
+}
+
+/// A wheel, which casts a ray down its local [Vec3::NEG_Y] axis to find the ground,
+/// then pushes its parent up with a spring-damper suspension and grips the ground with tyre friction.
+///
+/// The wheel rolls along its local [Vec3::NEG_Z] axis, turned about its local [Vec3::Y] axis by [RaycastWheel::steering].
+/// Its parent's own colliders are ignored by the ray, and the ground is assumed to be stationary.
+/// Writes [InternalFrame::World] [InternalForce] and [InternalTorque]s (inserted automatically if missing)
+/// every time [ParentingSystemSet::UpdateInternalForces](crate::ParentingSystemSet::UpdateInternalForces) runs.
+///
+/// Requires the opt-in `wheels` feature.
+#[derive(Reflect, Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct RaycastWheel {
+	/// The radius of the wheel, in meters. A wheel without a positive radius exerts nothing,
+	/// which is reported as a [ParentingIssue::InvalidWheelRadius]
+	pub radius: f32,
+	/// How far the wheel's center hangs below the child when the suspension is fully extended, in meters
+	pub suspension_length: f32,
+	/// The suspension's spring constant, in Newtons per meter
+	pub stiffness: f32,
+	/// The suspension's damping, in Newton seconds per meter
+	pub damping: f32,
+	/// The friction coefficient along the wheel's rolling direction
+	pub longitudinal_friction: f32,
+	/// The friction coefficient across the wheel's rolling direction
+	pub lateral_friction: f32,
+	/// How strongly the tyre resists sliding, in Newton seconds per meter, before it reaches the friction limit.
+	/// Defaults to [RaycastWheel::damping]
+	pub slip_damping: f32,
+	/// The torque driving the wheel forwards, in Newton meters. Negative values drive it backwards
+	pub drive_torque: f32,
+	/// The torque braking the wheel, in Newton meters
+	pub brake_torque: f32,
+	/// The angle that the wheel is steered by, in radians
+	pub steering: f32,
+	#[serde(skip)]
+	contact: Option<WheelContact>,
+}
+
+impl RaycastWheel {
+	/// Creates an undriven and unsteered [RaycastWheel], with friction coefficients of `1.0`
+	pub fn new(radius: f32, suspension_length: f32, stiffness: f32, damping: f32) -> Self {
+		Self {
+			radius,
+			suspension_length,
+			stiffness,
+			damping,
+			longitudinal_friction: 1.0,
+			lateral_friction: 1.0,
+			slip_damping: damping,
+			drive_torque: 0.0,
+			brake_torque: 0.0,
+			steering: 0.0,
+			contact: None,
+		}
+	}
+
+	pub fn with_friction(mut self, longitudinal: f32, lateral: f32) -> Self {
+		self.longitudinal_friction = longitudinal;
+		self.lateral_friction = lateral;
+		self
+	}
+
+	pub fn with_slip_damping(mut self, slip_damping: f32) -> Self {
+		self.slip_damping = slip_damping;
+		self
+	}
+
+	/// Where the wheel touched the ground the last time it was updated, or [None] if it is in the air
+	pub fn get_contact(&self) -> Option<WheelContact> {
+		self.contact
+	}
+
+	pub fn is_grounded(&self) -> bool {
+		self.contact.is_some()
+	}
+
+	/// How far the wheel's center currently hangs below the child, e.g. for positioning a visual wheel
+	pub fn get_suspension_travel(&self) -> f32 {
+		self.suspension_length - self.contact.map_or(0.0, |contact| contact.compression)
+	}
+
+	/// Returns `true` if the radius is positive, so that the wheel's torques can be turned into forces
+	pub fn has_valid_radius(&self) -> bool {
+		self.radius > 0.0 && self.radius.is_finite()
+	}
+
+	/// The local rotation of the wheel, including its steering
+	fn steered_rotation(&self, child_relative_rotation: Quat) -> Quat {
+		child_relative_rotation * Quat::from_rotation_y(self.steering)
+	}
+
+	/// The suspension and tyre forces, given the ground contact and the wheel's velocity at the contact point.
+	/// Returns the force, and the load on the suspension
+	fn compute_force(
+		&self,
+		up: Vec3,
+		forward: Vec3,
+		normal: Vec3,
+		compression: f32,
+		velocity: Vec3,
+	) -> (Vec3, f32) {
+		// compressing the suspension means moving against `up`
+		let compression_rate = -velocity.dot(up);
+		let load = (self.stiffness * compression + self.damping * compression_rate).max(0.0);
+
+		let forward = (forward - normal * forward.dot(normal)).normalize_or_zero();
+		let side = forward.cross(normal).normalize_or_zero();
+
+		let forward_speed = velocity.dot(forward);
+		let max_brake = self.brake_torque.abs() / self.radius;
+		let brake = (-forward_speed * self.slip_damping).clamp(-max_brake, max_brake);
+		let max_longitudinal = self.longitudinal_friction * load;
+		let longitudinal =
+			(self.drive_torque / self.radius + brake).clamp(-max_longitudinal, max_longitudinal);
+
+		let max_lateral = self.lateral_friction * load;
+		let lateral = (-velocity.dot(side) * self.slip_damping).clamp(-max_lateral, max_lateral);
+
+		(up * load + forward * longitudinal + side * lateral, load)
+	}
+}
+
+impl crate::ParentingPlugin {
+	/// Inserts an [InternalForce] and [InternalTorque] on every [RaycastWheel] that doesn't have them yet.
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn prepare_wheels(
+		mut commands: Commands,
+		wheels: Query<
+			(Entity, Has<InternalForce>, Has<InternalTorque>),
+			(
+				With<RaycastWheel>,
+				Or<(Without<InternalForce>, Without<InternalTorque>)>,
+			),
+		>,
+	) {
+		for (entity, has_force, has_torque) in wheels.iter() {
+			let mut entity_commands = commands.entity(entity);
+			if !has_force {
+				entity_commands.insert(InternalForce::new_global(Vec3::ZERO));
+			}
+			if !has_torque {
+				entity_commands.insert(InternalTorque::new_global(Vec3::ZERO));
+			}
+		}
+	}
+
+	/// Casts every [RaycastWheel]'s ray, and writes its suspension and tyre forces into its [InternalForce] and [InternalTorque].
+	/// This is automatically scheduled in [ParentingPlugin](crate::ParentingPlugin)
+	pub(crate) fn update_wheels(
+		mut wheels: Query<
+			(
+				Entity,
+				&mut RaycastWheel,
+				&mut InternalForce,
+				&mut InternalTorque,
+			),
+			Without<RigidBody>,
+		>,
+		parents: Query<
+			(
+				&Position,
+				&Rotation,
+				&LinearVelocity,
+				&AngularVelocity,
+				&CenterOfMass,
+				&GlobalTransform,
+			),
+			With<RigidBody>,
+		>,
+		colliders: Query<&ColliderParent>,
+		ancestors: RigidBodyAncestors,
+		spatial_query: SpatialQuery,
+	) {
+		for (child, mut wheel, mut internal_force, mut internal_torque) in wheels.iter_mut() {
+			if !wheel.has_valid_radius() {
+				// reported by `diagnose`
+				wheel.contact = None;
+				*internal_force = InternalForce::new_global(Vec3::ZERO);
+				*internal_torque = InternalTorque::new_global(Vec3::ZERO);
+				continue;
+			}
+			let Some((rigid_body, child_relative_transform)) = ancestors.find(child) else {
+				continue;
+			};
+			let Ok((
+				position,
+				rotation,
+				linear_velocity,
+				angular_velocity,
+				center_of_mass,
+				parent_global_transform,
+			)) = parents.get(rigid_body)
+			else {
+				continue;
+			};
+
+			let scale = parent_global_transform.compute_transform().scale;
+			let origin = world_point(
+				child_relative_transform.translation,
+				scale,
+				position.0,
+				rotation.0,
+			);
+			let wheel_rotation = rotation.0 * wheel.steered_rotation(child_relative_transform.rotation);
+			let up = wheel_rotation * Vec3::Y;
+
+			let hit = Direction3d::new(-up).ok().and_then(|down| {
+				spatial_query.cast_ray_predicate(
+					origin,
+					down,
+					wheel.suspension_length + wheel.radius,
+					true,
+					SpatialQueryFilter::default(),
+					&|entity| {
+						colliders
+							.get(entity)
+							.map_or(true, |collider_parent| collider_parent.get() != rigid_body)
+					},
+				)
+			});
+
+			let mut force = Vec3::ZERO;
+			let mut torque = Vec3::ZERO;
+			wheel.contact = hit.map(|hit| {
+				let point = origin - up * hit.time_of_impact;
+				let compression = wheel.suspension_length + wheel.radius - hit.time_of_impact;
+
+				let lever_arm = world_lever_arm(
+					child_relative_transform.translation,
+					scale,
+					rotation.0,
+					center_of_mass.0,
+				) + (point - origin);
+				let velocity = linear_velocity.0 + angular_velocity.0.cross(lever_arm);
+
+				let load;
+				(force, load) = wheel.compute_force(
+					up,
+					wheel_rotation * Vec3::NEG_Z,
+					hit.normal,
+					compression,
+					velocity,
+				);
+				// the force is applied at the child's position, not the contact point
+				torque = (point - origin).cross(force);
+
+				WheelContact {
+					entity: hit.entity,
+					point,
+					normal: hit.normal,
+					compression,
+					load,
+				}
+			});
+
+			*internal_force = InternalForce::new_global(force);
+			*internal_torque = InternalTorque::new_global(torque);
+		}
+	}
+}
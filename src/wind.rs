@@ -0,0 +1,68 @@
+//! The wind, which [AeroSurface]s feel as airflow.
+
+use std::f32::consts::TAU;
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+/// The velocity of the air everywhere in the world, which every [AeroSurface] computes its airflow relative to.
+/// Defaults to still air
+#[derive(Resource, Clone)]
+pub enum WindField {
+	/// The same wind everywhere, in meters per second
+	Constant(Vec3),
+	/// A steady `mean` wind, with `1 - cos` gusts adding up to `gust` on top of it every `period` seconds.
+	/// The gusts are carried along by the mean wind, so they reach downwind positions later
+	Gusty { mean: Vec3, gust: Vec3, period: f32 },
+	/// Returns the wind at a world position and time (in seconds of the physics clock)
+	Function(Arc<dyn Fn(Vec3, f32) -> Vec3 + Send + Sync>),
+}
+
+impl Default for WindField {
+	fn default() -> Self {
+		Self::Constant(Vec3::ZERO)
+	}
+}
+
+impl std::fmt::Debug for WindField {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			WindField::Constant(wind) => f.debug_tuple("Constant").field(wind).finish(),
+			WindField::Gusty { mean, gust, period } => f
+				.debug_struct("Gusty")
+				.field("mean", mean)
+				.field("gust", gust)
+				.field("period", period)
+				.finish(),
+			WindField::Function(_) => f.write_str("Function(..)"),
+		}
+	}
+}
+
+impl WindField {
+	/// Creates a [WindField::Function] from a closure
+	pub fn from_fn(wind: impl Fn(Vec3, f32) -> Vec3 + Send + Sync + 'static) -> Self {
+		Self::Function(Arc::new(wind))
+	}
+
+	/// The wind's velocity at a world `position` and time
+	pub fn velocity_at(&self, position: Vec3, elapsed_seconds: f32) -> Vec3 {
+		match self {
+			WindField::Constant(wind) => *wind,
+			WindField::Gusty { mean, gust, period } => {
+				if *period <= 0.0 {
+					return *mean;
+				}
+				// how long ago the air at `position` passed the origin
+				let travel_time = if *mean == Vec3::ZERO {
+					0.0
+				} else {
+					position.dot(*mean) / mean.length_squared()
+				};
+				let phase = (elapsed_seconds - travel_time) / period * TAU;
+				*mean + *gust * 0.5 * (1.0 - phase.cos())
+			}
+			WindField::Function(wind) => wind(position, elapsed_seconds),
+		}
+	}
+}
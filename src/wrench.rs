@@ -0,0 +1,186 @@
+//! Accumulating internal forces and torques on a parent [RigidBody].
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The total force and torque that a [RigidBody]'s children exert on it, in world space.
+///
+/// This is added on top of whatever the parent's [ExternalForce] and [ExternalTorque] hold
+/// right before each physics step, and taken off again straight after it,
+/// so forces applied by the user are left untouched (and never include the children's),
+/// and both persistent and non-persistent [ExternalForce]s and [ExternalTorque]s are supported.
+/// See [InternalWrenchLedger] for what each child contributed.
+///
+/// Automatically inserted on a [RigidBody] by the [ParentingPlugin](crate::ParentingPlugin)
+/// when it has a descendant with an [InternalForce](crate::InternalForce)
+/// or [InternalTorque](crate::InternalTorque).
+#[derive(Reflect, Component, Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct InternalWrench {
+	force: Vec3,
+	torque: Vec3,
+	/// What the parent's [ExternalForce] held before the wrench was added to it for a physics step
+	#[reflect(ignore)]
+	#[serde(skip)]
+	user_force: Option<ExternalForce>,
+	/// What the parent's [ExternalTorque] held before the wrench was added to it for a physics step
+	#[reflect(ignore)]
+	#[serde(skip)]
+	user_torque: Option<ExternalTorque>,
+}
+
+impl InternalWrench {
+	/// Returns the total world space force exerted by the children,
+	/// as of the last time [ParentingSystemSet::PropagateInternalForces](crate::ParentingSystemSet::PropagateInternalForces) ran.
+	pub fn force(&self) -> Vec3 {
+		self.force
+	}
+
+	/// Returns the total world space torque exerted by the children (including the torque caused by
+	/// forces applied away from the center of mass),
+	/// as of the last time [ParentingSystemSet::PropagateInternalForces](crate::ParentingSystemSet::PropagateInternalForces) ran.
+	pub fn torque(&self) -> Vec3 {
+		self.torque
+	}
+
+	/// Adds a world space `force` at a world space `lever_arm` relative to the center of mass,
+	/// which will also cause torque to be applied.
+	pub(crate) fn apply_force_at_lever_arm(&mut self, force: Vec3, lever_arm: Vec3) {
+		self.force += force;
+		self.torque += lever_arm.cross(force);
+	}
+
+	/// Adds a world space `torque`.
+	pub(crate) fn apply_torque(&mut self, torque: Vec3) {
+		self.torque += torque;
+	}
+
+	/// Resets the wrench so that it can be accumulated again.
+	pub(crate) fn reset(&mut self) {
+		self.force = Vec3::ZERO;
+		self.torque = Vec3::ZERO;
+	}
+
+	/// Adds the wrench on top of the parent's [ExternalForce] and [ExternalTorque] for a single physics step,
+	/// remembering what they held so that [InternalWrench::remove_from] can restore them.
+	pub(crate) fn apply_to(
+		&mut self,
+		external_force: &mut Mut<ExternalForce>,
+		external_torque: &mut Mut<ExternalTorque>,
+	) {
+		// avoid triggering change detection unnecessarily
+		if self.force != Vec3::ZERO {
+			self.user_force = Some(**external_force);
+			external_force.apply_force(self.force);
+		}
+		if self.torque != Vec3::ZERO {
+			self.user_torque = Some(**external_torque);
+			external_torque.apply_torque(self.torque);
+		}
+	}
+
+	/// Restores the parent's [ExternalForce] and [ExternalTorque] to what they held before
+	/// [InternalWrench::apply_to], before bevy_xpbd clears the non-persistent ones.
+	pub(crate) fn remove_from(
+		&mut self,
+		external_force: &mut Mut<ExternalForce>,
+		external_torque: &mut Mut<ExternalTorque>,
+	) {
+		if let Some(user_force) = self.user_force.take() {
+			**external_force = user_force;
+		}
+		if let Some(user_torque) = self.user_torque.take() {
+			**external_torque = user_torque;
+		}
+	}
+}
+
+/// What a single child contributed to its parent's [InternalWrench], see [InternalWrenchLedger].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct WrenchContribution {
+	/// The child exerting the force and/or torque
+	pub child: Entity,
+	/// The world space force exerted by the child
+	pub force: Vec3,
+	/// The world space position of the child, where its force is applied
+	pub point: Vec3,
+	/// The world space torque exerted by the child,
+	/// including the torque caused by its force being applied away from the center of mass
+	pub torque: Vec3,
+}
+
+/// A breakdown of a [RigidBody]'s [InternalWrench] into what each of its children contributed,
+/// e.g. for displaying how much thrust a ship is producing.
+///
+/// Updated whenever [ParentingSystemSet::PropagateInternalForces](crate::ParentingSystemSet::PropagateInternalForces) runs.
+/// [InternalImpulse](crate::InternalImpulse)s are one-shot and applied directly to the [ExternalImpulse],
+/// so are not listed.
+///
+/// Automatically inserted alongside the [InternalWrench].
+#[derive(Reflect, Component, Debug, Clone, Default, PartialEq)]
+#[reflect(Component)]
+pub struct InternalWrenchLedger {
+	contributions: Vec<WrenchContribution>,
+}
+
+impl InternalWrenchLedger {
+	/// Every child's contribution, in no particular order.
+	/// A child with both an [InternalForce](crate::InternalForce) and an
+	/// [InternalTorque](crate::InternalTorque) is only listed once.
+	pub fn contributions(&self) -> &[WrenchContribution] {
+		&self.contributions
+	}
+
+	/// Returns the contribution of a single `child`, if it exerted any force or torque
+	pub fn get(&self, child: Entity) -> Option<&WrenchContribution> {
+		self
+			.contributions
+			.iter()
+			.find(|contribution| contribution.child == child)
+	}
+
+	/// Returns the net world space force of every child, the same as [InternalWrench::force]
+	pub fn force(&self) -> Vec3 {
+		self
+			.contributions
+			.iter()
+			.map(|contribution| contribution.force)
+			.sum()
+	}
+
+	/// Returns the net world space torque of every child, the same as [InternalWrench::torque]
+	pub fn torque(&self) -> Vec3 {
+		self
+			.contributions
+			.iter()
+			.map(|contribution| contribution.torque)
+			.sum()
+	}
+
+	/// Records a world space `force` and `torque` exerted by `child` at the world space `point`,
+	/// merging it with anything else the child already contributed.
+	pub(crate) fn record(&mut self, child: Entity, force: Vec3, point: Vec3, torque: Vec3) {
+		match self
+			.contributions
+			.iter_mut()
+			.find(|contribution| contribution.child == child)
+		{
+			Some(contribution) => {
+				contribution.force += force;
+				contribution.torque += torque;
+			}
+			None => self.contributions.push(WrenchContribution {
+				child,
+				force,
+				point,
+				torque,
+			}),
+		}
+	}
+
+	/// Forgets every contribution, keeping the allocation
+	pub(crate) fn clear(&mut self) {
+		self.contributions.clear();
+	}
+}
@@ -0,0 +1,178 @@
+mod utils;
+use utils::*;
+
+fn spawn_accelerated(
+	app: &mut App,
+	position: Vec3,
+	density: f32,
+	acceleration: InternalAcceleration,
+) -> (Entity, Entity) {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_translation(position)),
+			RigidBody::Dynamic,
+			Collider::cuboid(1.0, 2.0, 3.0),
+			ColliderDensity(density),
+			GravityScale(0.0),
+		))
+		.id();
+	let child = spawn_child(app, parent, Vec3::ZERO, acceleration);
+	(parent, child)
+}
+
+#[test]
+fn acceleration_ignores_parent_mass() {
+	let mut app = fixed_once_app();
+
+	let acceleration = InternalAcceleration::new_global(Vec3::X * 2.0, Vec3::ZERO);
+	let (light, _) = spawn_accelerated(&mut app, Vec3::ZERO, 1.0, acceleration);
+	let (heavy, heavy_child) = spawn_accelerated(&mut app, Vec3::Z * 10.0, 100.0, acceleration);
+
+	for _ in 0..60 {
+		app.update();
+	}
+
+	let light_velocity = get::<LinearVelocity>(light)(&mut app.world).0;
+	let heavy_velocity = get::<LinearVelocity>(heavy)(&mut app.world).0;
+	assert!(
+		light_velocity.distance(heavy_velocity) < 0.0001,
+		"{light_velocity} {heavy_velocity}"
+	);
+	// about a second of acceleration
+	assert!(
+		heavy_velocity.x > 1.9 && heavy_velocity.x <= 2.0001,
+		"{heavy_velocity}"
+	);
+
+	let mass = get::<Mass>(heavy)(&mut app.world).0;
+	let force = get::<InternalForce>(heavy_child)(&mut app.world).compute_naive_force();
+	assert!(force.distance(Vec3::X * 2.0 * mass) < 0.01);
+}
+
+#[test]
+fn angular_acceleration_uses_inertia() {
+	let mut app = fixed_once_app();
+
+	let (parent, child) = spawn_accelerated(
+		&mut app,
+		Vec3::ZERO,
+		10.0,
+		InternalAcceleration::new_parent_body(Vec3::ZERO, Vec3::Y),
+	);
+
+	for _ in 0..60 {
+		app.update();
+	}
+
+	let inertia = get::<Inertia>(parent)(&mut app.world).0;
+	let torque = get::<InternalTorque>(child)(&mut app.world).compute_naive_torque();
+	assert!(
+		torque.distance(Vec3::Y * inertia.y_axis.y) < 0.01,
+		"{torque}"
+	);
+
+	// spinning about a principal axis, so there is no gyroscopic effect
+	let angular_velocity = get::<AngularVelocity>(parent)(&mut app.world).0;
+	assert!(
+		angular_velocity.y > 0.95 && angular_velocity.y < 1.001,
+		"{angular_velocity}"
+	);
+}
+
+#[test]
+fn force_follows_mass_changes() {
+	let mut app = fixed_once_app();
+
+	let (parent, child) = spawn_accelerated(
+		&mut app,
+		Vec3::ZERO,
+		1.0,
+		InternalAcceleration::new_local(Vec3::Y, Vec3::ZERO),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	let mass = get::<Mass>(parent)(&mut app.world).0;
+	let force = get::<InternalForce>(child)(&mut app.world).compute_naive_force();
+	assert!(force.distance(Vec3::Y * mass) < 0.0001);
+
+	// an artist adds another collider
+	app
+		.world
+		.spawn((TransformBundle::default(), Collider::cuboid(1.0, 2.0, 3.0)))
+		.set_parent(parent);
+	for _ in 0..2 {
+		app.update();
+	}
+
+	let heavier_mass = get::<Mass>(parent)(&mut app.world).0;
+	assert!(heavier_mass > mass * 1.5, "{heavier_mass}");
+	let force = get::<InternalForce>(child)(&mut app.world).compute_naive_force();
+	assert!(force.distance(Vec3::Y * heavier_mass) < 0.0001);
+}
+
+#[test]
+fn strength_scales_acceleration() {
+	let mut app = fixed_once_app();
+
+	let (parent, child) = spawn_accelerated(
+		&mut app,
+		Vec3::ZERO,
+		1.0,
+		InternalAcceleration::new_global(Vec3::X, Vec3::ZERO),
+	);
+	app
+		.world
+		.entity_mut(child)
+		.insert(InternalForce::new_global(Vec3::ZERO).with_strength(0.5));
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let mass = get::<Mass>(parent)(&mut app.world).0;
+	let force = get::<InternalForce>(child)(&mut app.world).compute_naive_force();
+	assert!(force.distance(Vec3::X * 0.5 * mass) < 0.0001);
+}
+
+#[test]
+fn despawning_frame_entity_stops_acceleration() {
+	let mut app = fixed_once_app();
+
+	let frame = app.world.spawn(TransformBundle::default()).id();
+	let (parent, child) = spawn_accelerated(
+		&mut app,
+		Vec3::ZERO,
+		1.0,
+		InternalAcceleration::new_in_frame(InternalFrame::Entity(frame), Vec3::X, Vec3::Y),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	assert_ne!(
+		get::<InternalTorque>(child)(&mut app.world).compute_naive_torque(),
+		Vec3::ZERO
+	);
+
+	app.world.despawn(frame);
+	app.update();
+	assert_eq!(
+		get::<InternalForce>(child)(&mut app.world).compute_naive_force(),
+		Vec3::ZERO
+	);
+	assert_eq!(
+		get::<InternalTorque>(child)(&mut app.world).compute_naive_torque(),
+		Vec3::ZERO
+	);
+
+	// nothing keeps spinning the parent up
+	let angular_velocity = get::<AngularVelocity>(parent)(&mut app.world).0;
+	for _ in 0..5 {
+		app.update();
+	}
+	let after = get::<AngularVelocity>(parent)(&mut app.world).0;
+	assert!(after.distance(angular_velocity) < 0.0001, "{after}");
+}
@@ -0,0 +1,239 @@
+mod utils;
+use std::time::Duration;
+
+use bevy::time::TimeUpdateStrategy;
+use bevy_xpbd_3d::plugins::setup::Physics;
+use utils::*;
+
+const DELTA: f32 = 1.0 / 60.0;
+
+/// Runs a single update, returning the change in the parent's [LinearVelocity] times its [Mass],
+/// i.e. the force that was applied to it over one step of [DELTA]
+fn applied_force(app: &mut App, parent: Entity) -> Vec3 {
+	let before = get::<LinearVelocity>(parent)(&mut app.world).0;
+	app.update();
+	let after = get::<LinearVelocity>(parent)(&mut app.world).0;
+	let mass = get::<Mass>(parent)(&mut app.world).0;
+	(after - before) * mass / DELTA
+}
+
+fn assert_close(actual: Vec3, expected: Vec3) {
+	assert!(
+		(actual - expected).length() < 1e-2,
+		"{:?} isn't {:?}",
+		actual,
+		expected
+	);
+}
+
+proptest! {
+	#[test]
+	fn persistent_parent_receives_child_forces(user_force in -100.0f32 .. 100.0f32, internal_force in -100.0f32 .. 100.0f32) {
+		let mut app = fixed_once_app();
+
+		let user_force = Vec3::X * user_force;
+		let internal_force = Vec3::Y * internal_force;
+		let parent = spawn_parent(&mut app, ExternalForce::new(user_force).with_persistence(true));
+		spawn_child(&mut app, parent, Vec3::ZERO, InternalForce::new_global(internal_force));
+
+		let get_external_force = get::<ExternalForce>(parent);
+		let get_internal_wrench = get::<InternalWrench>(parent);
+
+		for _ in 0..SETUP_ITERATIONS {
+			app.update();
+		}
+
+		// the internal force is applied on top of the user's force, which is never changed,
+		// and doesn't accumulate over frames
+		for _ in 0..10 {
+			assert_close(applied_force(&mut app, parent), user_force + internal_force);
+			assert_eq!(get_internal_wrench(&mut app.world).force(), internal_force);
+			assert_eq!(get_external_force(&mut app.world).force(), user_force);
+		}
+	}
+}
+
+#[test]
+fn persistent_torque_receives_child_torques() {
+	let mut app = fixed_once_app();
+
+	let user_torque = Vec3::X * 5.0;
+	let parent = spawn_parent(
+		&mut app,
+		ExternalTorque::new(user_torque).with_persistence(true),
+	);
+	spawn_child(
+		&mut app,
+		parent,
+		Vec3::ZERO,
+		InternalTorque::new_global(Vec3::Z * 10.0),
+	);
+
+	let get_external_torque = get::<ExternalTorque>(parent);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	for _ in 0..10 {
+		app.update();
+		assert_eq!(get_external_torque(&mut app.world).torque(), user_torque);
+		assert_eq!(
+			get::<InternalWrench>(parent)(&mut app.world).torque(),
+			Vec3::Z * 10.0
+		);
+	}
+	// both torques spin the parent
+	let angular_velocity = get::<AngularVelocity>(parent)(&mut app.world).0;
+	assert!(angular_velocity.x > 0.0, "{:?}", angular_velocity);
+	assert!(angular_velocity.z > 0.0, "{:?}", angular_velocity);
+}
+
+#[test]
+fn removing_child_removes_its_force() {
+	let mut app = fixed_once_app();
+
+	let user_force = Vec3::X * 5.0;
+	let parent = spawn_parent(
+		&mut app,
+		ExternalForce::new(user_force).with_persistence(true),
+	);
+	let child = spawn_child(
+		&mut app,
+		parent,
+		Vec3::ZERO,
+		InternalForce::new_global(Vec3::Y * 10.0),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	assert_close(applied_force(&mut app, parent), user_force + Vec3::Y * 10.0);
+
+	app.world.entity_mut(child).remove::<InternalForce>();
+	assert_close(applied_force(&mut app, parent), user_force);
+	assert_eq!(
+		get::<ExternalForce>(parent)(&mut app.world).force(),
+		user_force
+	);
+}
+
+#[test]
+fn overwriting_persistent_force_keeps_user_value() {
+	let mut app = fixed_once_app();
+
+	let parent = spawn_parent(
+		&mut app,
+		ExternalForce::new(Vec3::ZERO).with_persistence(true),
+	);
+	spawn_child(
+		&mut app,
+		parent,
+		Vec3::ZERO,
+		InternalForce::new_global(Vec3::Y * 10.0),
+	);
+
+	let get_external_force = get::<ExternalForce>(parent);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	// the user sets their persistent force every frame, overwriting it
+	for i in 0..10 {
+		let user_force = Vec3::X * i as f32;
+		*app.world.get_mut::<ExternalForce>(parent).unwrap() =
+			ExternalForce::new(user_force).with_persistence(true);
+		assert_close(applied_force(&mut app, parent), user_force + Vec3::Y * 10.0);
+		assert_eq!(get_external_force(&mut app.world).force(), user_force);
+	}
+}
+
+#[test]
+fn adding_to_persistent_force_keeps_user_value() {
+	let mut app = fixed_once_app();
+
+	let parent = spawn_parent(
+		&mut app,
+		ExternalForce::new(Vec3::ZERO).with_persistence(true),
+	);
+	let child = spawn_child(
+		&mut app,
+		parent,
+		Vec3::ZERO,
+		InternalForce::new_global(Vec3::Y * 10.0),
+	);
+
+	let get_external_force = get::<ExternalForce>(parent);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	// the user adds to their persistent force every frame, instead of overwriting it
+	for i in 1..=10 {
+		app
+			.world
+			.get_mut::<ExternalForce>(parent)
+			.unwrap()
+			.apply_force(Vec3::X);
+		let user_force = Vec3::X * i as f32;
+		assert_close(applied_force(&mut app, parent), user_force + Vec3::Y * 10.0);
+		assert_eq!(get_external_force(&mut app.world).force(), user_force);
+	}
+
+	// shutting the child off leaves only the user's force
+	app.world.entity_mut(child).remove::<InternalForce>();
+	let user_force = get_external_force(&mut app.world).force();
+	assert_close(applied_force(&mut app, parent), user_force);
+	assert_eq!(get_external_force(&mut app.world).force(), user_force);
+}
+
+#[test]
+fn frame_without_physics_step_applies_nothing() {
+	let mut app = test_app(None);
+	app.insert_resource(Time::new_with(Physics::fixed_hz(60.0)));
+	app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+
+	let parent = spawn_parent(
+		&mut app,
+		ExternalForce::new(Vec3::ZERO).with_persistence(false),
+	);
+	spawn_child(
+		&mut app,
+		parent,
+		Vec3::ZERO,
+		InternalForce::new_global(Vec3::Y * 10.0),
+	);
+
+	let get_external_force = get::<ExternalForce>(parent);
+
+	// the first frame always runs a step
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	// no time passes, so no steps run, and the user's force isn't cleared
+	for i in 1..=3 {
+		app
+			.world
+			.get_mut::<ExternalForce>(parent)
+			.unwrap()
+			.apply_force(Vec3::X);
+		assert_close(applied_force(&mut app, parent), Vec3::ZERO);
+		assert_eq!(
+			get_external_force(&mut app.world).force(),
+			Vec3::X * i as f32
+		);
+	}
+
+	// the next step applies everything the user added, but the internal force only once
+	app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+		1.0 / 60.0,
+	)));
+	assert_close(
+		applied_force(&mut app, parent),
+		Vec3::X * 3.0 + Vec3::Y * 10.0,
+	);
+	assert_eq!(get_external_force(&mut app.world).force(), Vec3::ZERO);
+}
@@ -0,0 +1,140 @@
+mod utils;
+use bevy_xpbd_3d_parenting::{AirDensity, LiftCurve};
+use utils::*;
+
+fn spawn_surface(
+	app: &mut App,
+	linear_velocity: Vec3,
+	angular_velocity: Vec3,
+	child_transform: Transform,
+	surface: AeroSurface,
+) -> (Entity, Entity) {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+			LinearVelocity(linear_velocity),
+			AngularVelocity(angular_velocity),
+		))
+		.id();
+	let child = app
+		.world
+		.spawn((TransformBundle::from_transform(child_transform), surface))
+		.set_parent(parent)
+		.id();
+	(parent, child)
+}
+
+fn force(app: &mut App, child: Entity) -> Vec3 {
+	let internal_force = get::<InternalForce>(child)(&mut app.world);
+	assert_eq!(internal_force.get_frame(), InternalFrame::World);
+	internal_force.compute_naive_force()
+}
+
+#[test]
+fn level_surface_only_drags() {
+	let mut app = test_app(None);
+
+	let surface = AeroSurface::new(2.0).with_drag(0.1, 0.0);
+	let (_, child) = spawn_surface(
+		&mut app,
+		Vec3::NEG_Z * 10.0,
+		Vec3::ZERO,
+		Transform::default(),
+		surface,
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let density = app.world.resource::<AirDensity>().0;
+	let expected = Vec3::Z * 0.5 * density * 100.0 * 2.0 * 0.1;
+	let force = force(&mut app, child);
+	assert!(force.distance(expected) < 0.001, "{force} != {expected}");
+}
+
+#[test]
+fn angle_of_attack_produces_lift() {
+	let mut app = test_app(None);
+
+	let angle = 0.1;
+	let slope = 5.0;
+	let surface = AeroSurface::new(1.0)
+		.with_lift_curve(LiftCurve::Linear {
+			slope,
+			stall_angle: 0.3,
+		})
+		.with_drag(0.0, 0.0);
+	// pitched nose up, flying forward
+	let (_, child) = spawn_surface(
+		&mut app,
+		Vec3::NEG_Z * 10.0,
+		Vec3::ZERO,
+		Transform::from_rotation(Quat::from_rotation_x(angle)),
+		surface,
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let density = app.world.resource::<AirDensity>().0;
+	let expected = Vec3::Y * 0.5 * density * 100.0 * slope * angle;
+	let force = force(&mut app, child);
+	assert!(force.distance(expected) < 0.01, "{force} != {expected}");
+}
+
+#[test]
+fn spinning_parent_moves_surface_through_air() {
+	let mut app = test_app(None);
+
+	// spinning around Y moves a surface at +X towards -Z, i.e. forwards
+	let surface = AeroSurface::new(1.0).with_drag(0.1, 0.0);
+	let (_, child) = spawn_surface(
+		&mut app,
+		Vec3::ZERO,
+		Vec3::Y * 2.0,
+		Transform::from_translation(Vec3::X * 2.0),
+		surface,
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let density = app.world.resource::<AirDensity>().0;
+	let expected = Vec3::Z * 0.5 * density * 16.0 * 0.1;
+	let force = force(&mut app, child);
+	assert!(force.distance(expected) < 0.01, "{force} != {expected}");
+}
+
+#[test]
+fn lift_table_is_interpolated() {
+	let curve = LiftCurve::Table(vec![(-0.2, -1.0), (0.0, 0.0), (0.2, 1.5)]);
+
+	assert_eq!(curve.lift_coefficient(-1.0), -1.0);
+	assert_eq!(curve.lift_coefficient(0.0), 0.0);
+	assert!((curve.lift_coefficient(0.1) - 0.75).abs() < 0.0001);
+	assert_eq!(curve.lift_coefficient(1.0), 1.5);
+}
+
+proptest! {
+	#[test]
+	fn lift_is_perpendicular_to_airflow(
+		airflow in proptest::array::uniform3(-50f32..50.0),
+		angle in -3f32..3.0,
+	) {
+		let airflow = Vec3::from(airflow);
+		let surface = AeroSurface::new(1.0).with_drag(0.0, 0.0);
+		let force = surface.compute_force(Quat::from_rotation_x(angle), airflow, 1.0);
+
+		// spanwise flow is ignored, so compare with the flow in the plane of the chord and normal
+		let in_plane = Vec3::new(0.0, airflow.y, airflow.z);
+		assert!(force.dot(in_plane).abs() <= 0.001 * force.length() * in_plane.length() + 0.001);
+		assert!(force.x.abs() < 0.001);
+	}
+}
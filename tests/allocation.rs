@@ -0,0 +1,174 @@
+mod utils;
+use bevy_xpbd_3d_parenting::{allocate, Actuator};
+use utils::*;
+
+fn spawn_parent(app: &mut App, allocator: ThrustAllocator) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::cuboid(4.0, 1.0, 4.0),
+			GravityScale(0.0),
+			allocator,
+		))
+		.id()
+}
+
+fn spawn_child(app: &mut App, parent: Entity, translation: Vec3, bundle: impl Bundle) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_translation(translation)),
+			bundle,
+		))
+		.set_parent(parent)
+		.id()
+}
+
+fn strength(app: &mut App, child: Entity) -> f32 {
+	get::<InternalForce>(child)(&mut app.world).get_strength()
+}
+
+#[test]
+fn symmetric_children_share_force() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app, ThrustAllocator::new(Vec3::Y * 20.0, Vec3::ZERO));
+	let corners = [
+		Vec3::new(1.0, 0.0, 1.0),
+		Vec3::new(-1.0, 0.0, 1.0),
+		Vec3::new(1.0, 0.0, -1.0),
+		Vec3::new(-1.0, 0.0, -1.0),
+	];
+	let children = corners.map(|corner| {
+		spawn_child(
+			&mut app,
+			parent,
+			corner,
+			(
+				InternalForce::new_local(Vec3::Y),
+				AllocationLimits::new(0.0, 10.0),
+			),
+		)
+	});
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	for child in children {
+		let strength = strength(&mut app, child);
+		assert!((strength - 5.0).abs() < 0.01, "{strength} != 5.0");
+	}
+	let wrench = get::<InternalWrench>(parent)(&mut app.world);
+	assert!(wrench.force().distance(Vec3::Y * 20.0) < 0.05);
+	assert!(wrench.torque().length() < 0.05);
+}
+
+#[test]
+fn torque_is_allocated() {
+	let mut app = test_app(None);
+
+	// right - left = 2 and right + left = 10
+	let parent = spawn_parent(
+		&mut app,
+		ThrustAllocator::new(Vec3::Y * 10.0, Vec3::Z * 2.0),
+	);
+	let bundle = || {
+		(
+			InternalForce::new_local(Vec3::Y),
+			AllocationLimits::new(0.0, 10.0),
+		)
+	};
+	let left = spawn_child(&mut app, parent, Vec3::NEG_X, bundle());
+	let right = spawn_child(&mut app, parent, Vec3::X, bundle());
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert!((strength(&mut app, left) - 4.0).abs() < 0.01);
+	assert!((strength(&mut app, right) - 6.0).abs() < 0.01);
+	let allocator = get::<ThrustAllocator>(parent)(&mut app.world);
+	assert!(allocator.get_achieved_force().distance(Vec3::Y * 10.0) < 0.05);
+	assert!(allocator.get_achieved_torque().distance(Vec3::Z * 2.0) < 0.05);
+}
+
+#[test]
+fn limits_are_respected() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app, ThrustAllocator::new(Vec3::Y * 100.0, Vec3::ZERO));
+	let children = [Vec3::X, Vec3::NEG_X].map(|translation| {
+		spawn_child(
+			&mut app,
+			parent,
+			translation,
+			(
+				InternalForce::new_local(Vec3::Y),
+				AllocationLimits::new(1.0, 10.0),
+			),
+		)
+	});
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	for child in children {
+		assert_eq!(strength(&mut app, child), 10.0);
+	}
+	let allocator = get::<ThrustAllocator>(parent)(&mut app.world);
+	assert!(allocator.get_achieved_force().distance(Vec3::Y * 20.0) < 0.001);
+}
+
+#[test]
+fn thruster_throttles_are_allocated() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app, ThrustAllocator::new(Vec3::Y * 50.0, Vec3::ZERO));
+	let children = [Vec3::X, Vec3::NEG_X].map(|translation| {
+		spawn_child(
+			&mut app,
+			parent,
+			translation,
+			(Thruster::new(100.0), AllocationLimits::new(0.0, 1.0)),
+		)
+	});
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	for child in children {
+		let throttle = get::<Thruster>(child)(&mut app.world).get_throttle();
+		assert!((throttle - 0.25).abs() < 0.001, "{throttle} != 0.25");
+	}
+	let wrench = get::<InternalWrench>(parent)(&mut app.world);
+	assert!(wrench.force().distance(Vec3::Y * 50.0) < 0.1);
+}
+
+proptest! {
+	#[test]
+	fn allocation_is_within_limits(
+		force in proptest::array::uniform3(-100f32..100.0),
+		torque in proptest::array::uniform3(-100f32..100.0),
+		desired in proptest::array::uniform3(-100f32..100.0),
+		min in -10f32..0.0,
+		max in 0f32..10.0,
+	) {
+		let actuators = [
+			Actuator { force: Vec3::from(force), torque: Vec3::from(torque), min, max },
+			Actuator { force: Vec3::X, torque: Vec3::Z, min, max },
+			Actuator { force: Vec3::NEG_Y, torque: Vec3::ZERO, min, max },
+		];
+
+		let strengths = allocate(&actuators, Vec3::from(desired), Vec3::ZERO);
+
+		assert_eq!(strengths.len(), actuators.len());
+		for strength in strengths {
+			assert!((min..=max).contains(&strength));
+		}
+	}
+}
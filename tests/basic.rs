@@ -40,38 +40,35 @@ proptest! {
 }
 
 #[test]
-fn external_forces_clear() {
+fn external_forces_untouched() {
 	let mut app = test_app(None);
 
+	// a body this crate has nothing to do with
 	let force = Vec3::new(0.0, 0.0, 100.0);
 	let parent = app
 		.world
 		.spawn((
 			TransformBundle::default(),
 			RigidBody::Dynamic,
-			ExternalForce::new(force).with_persistence(false),
+			ExternalForce::new(force).with_persistence(true),
 			Collider::capsule(1.0, 1.0),
 		))
 		.id();
 
 	let get = get::<ExternalForce>(parent);
-	let set = set::<ExternalForce>(parent);
 
 	for _ in 0..SETUP_ITERATIONS {
 		app.update();
 	}
 
-	for i in 0..10 {
+	for _ in 0..10 {
 		let current = get(&mut app.world).force();
 		#[cfg(feature = "debug")]
-		println!("i: {i}, current: {:?}", current);
-		assert_eq!(current, Vec3::ZERO);
-		set(
-			&mut app.world,
-			ExternalForce::new(force + Vec3::X * i as f32).with_persistence(false),
-		);
+		println!("current: {:?}", current);
+		assert_eq!(current, force);
 		app.update();
 	}
+	assert!(app.world.entity(parent).get::<InternalWrench>().is_none());
 }
 
 #[ignore = "bevy_xpbd actually clears forces properly, which was not observed in previous versions"]
@@ -0,0 +1,256 @@
+mod utils;
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::time::Duration;
+
+use bevy::time::TimeUpdateStrategy;
+use bevy_xpbd_3d::plugins::setup::Physics;
+use bevy_xpbd_3d_parenting::{BuoyancyShape, WaterSurface};
+use utils::*;
+
+fn spawn_volume(
+	app: &mut App,
+	parent_translation: Vec3,
+	gravity_scale: f32,
+	volume: BuoyancyVolume,
+) -> (Entity, Entity) {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_translation(parent_translation)),
+			RigidBody::Dynamic,
+			Collider::cuboid(2.0, 2.0, 2.0),
+			ColliderDensity(500.0),
+			GravityScale(gravity_scale),
+		))
+		.id();
+	let child = app
+		.world
+		.spawn((TransformBundle::default(), volume))
+		.set_parent(parent)
+		.id();
+	(parent, child)
+}
+
+fn buoyancy(app: &mut App, child: Entity) -> Vec3 {
+	get::<InternalForce>(child)(&mut app.world).compute_naive_force()
+}
+
+fn weight_of_water(app: &App, volume: f32) -> f32 {
+	let water = app.world.resource::<Water>();
+	let gravity = app.world.resource::<Gravity>();
+	water.density * volume * -gravity.0.y
+}
+
+#[test]
+fn half_submerged_sphere() {
+	let mut app = test_app(None);
+
+	let (_, child) = spawn_volume(&mut app, Vec3::ZERO, 0.0, BuoyancyVolume::sphere(1.0));
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let expected = Vec3::Y * weight_of_water(&app, 2.0 / 3.0 * PI);
+	let force = buoyancy(&mut app, child);
+	assert!(
+		force.distance(expected) < expected.length() * 0.001,
+		"{force} != {expected}"
+	);
+	// the center of buoyancy is directly below the child, so there is no torque
+	let torque = get::<InternalTorque>(child)(&mut app.world).compute_naive_torque();
+	assert!(torque.length() < 0.01);
+}
+
+#[test]
+fn sphere_center_of_buoyancy() {
+	let sphere = BuoyancyShape::Sphere { radius: 2.0 };
+
+	// a hemisphere's centroid is 3/8 of its radius from its flat face
+	let (fraction, center_of_buoyancy) = sphere.submerged(Vec3::ZERO, Quat::IDENTITY, 0.0).unwrap();
+	assert!((fraction - 0.5).abs() < 0.0001);
+	assert!(
+		center_of_buoyancy.distance(Vec3::NEG_Y * 0.75) < 0.0001,
+		"{center_of_buoyancy}"
+	);
+
+	// a fully submerged sphere's is its center
+	let center = Vec3::new(1.0, -5.0, 2.0);
+	let (fraction, center_of_buoyancy) = sphere.submerged(center, Quat::IDENTITY, 0.0).unwrap();
+	assert_eq!(fraction, 1.0);
+	assert!(center_of_buoyancy.distance(center) < 0.0001);
+}
+
+#[test]
+fn submerged_and_dry_cuboids() {
+	let mut app = test_app(None);
+
+	let volume = BuoyancyVolume::cuboid(Vec3::splat(0.5));
+	let (_, submerged) = spawn_volume(&mut app, Vec3::NEG_Y * 10.0, 0.0, volume);
+	let (_, dry) = spawn_volume(&mut app, Vec3::Y * 10.0, 0.0, volume);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let expected = Vec3::Y * weight_of_water(&app, 1.0);
+	assert!(buoyancy(&mut app, submerged).distance(expected) < expected.length() * 0.001);
+	assert_eq!(buoyancy(&mut app, dry), Vec3::ZERO);
+}
+
+#[test]
+fn water_surface_function() {
+	let mut app = test_app(None);
+	// a steep slope, which is 10 meters high above the origin
+	app.world.resource_mut::<Water>().surface =
+		WaterSurface::from_fn(|position, _time| 10.0 + position.x);
+
+	let (_, child) = spawn_volume(&mut app, Vec3::ZERO, 0.0, BuoyancyVolume::sphere(0.5));
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let expected = Vec3::Y * weight_of_water(&app, 4.0 / 3.0 * PI * 0.125);
+	assert!(buoyancy(&mut app, child).distance(expected) < expected.length() * 0.001);
+}
+
+#[test]
+fn cuboid_floats_at_equilibrium() {
+	let mut app = test_app(None);
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(60.0)));
+	app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+		1.0 / 60.0,
+	)));
+
+	// half as dense as water, so floats half submerged
+	let (parent, _) = spawn_volume(
+		&mut app,
+		Vec3::Y * 0.5,
+		1.0,
+		BuoyancyVolume::cuboid(Vec3::ONE).with_drag(20000.0, 20000.0),
+	);
+
+	for _ in 0..600 {
+		app.update();
+	}
+
+	let height = get::<Position>(parent)(&mut app.world).y;
+	assert!(height.abs() < 0.05, "floating at {height}");
+}
+
+fn spawn_tilted(app: &mut App, collider: Collider, tilt: f32, volume: BuoyancyVolume) -> Entity {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_rotation(Quat::from_rotation_z(tilt))),
+			RigidBody::Dynamic,
+			collider,
+			ColliderDensity(500.0),
+			GravityScale(0.0),
+		))
+		.id();
+	app
+		.world
+		.spawn((TransformBundle::default(), volume))
+		.set_parent(parent)
+		.id()
+}
+
+#[test]
+fn tilted_cuboid_is_righted() {
+	let mut app = test_app(None);
+
+	// a wide, flat cuboid, rolled either way about Z
+	let volume = BuoyancyVolume::cuboid(Vec3::new(2.0, 0.5, 1.0));
+	let collider = Collider::cuboid(4.0, 1.0, 2.0);
+	let rolled_left = spawn_tilted(&mut app, collider.clone(), 0.3, volume);
+	let rolled_right = spawn_tilted(&mut app, collider, -0.3, volume);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	// centered on the surface, so exactly half submerged however it is tilted
+	let expected = Vec3::Y * weight_of_water(&app, 4.0);
+	let force = buoyancy(&mut app, rolled_left);
+	assert!(
+		force.distance(expected) < expected.length() * 0.001,
+		"{force} != {expected}"
+	);
+
+	// the deeper side pushes up harder, rolling it back
+	let left_torque = get::<InternalTorque>(rolled_left)(&mut app.world).compute_naive_torque();
+	let right_torque = get::<InternalTorque>(rolled_right)(&mut app.world).compute_naive_torque();
+	assert!(left_torque.z < -1000.0, "{left_torque}");
+	assert!(
+		(left_torque + right_torque).length() < left_torque.length() * 0.001,
+		"{left_torque} and {right_torque} aren't opposite"
+	);
+}
+
+#[test]
+fn tilted_capsule_is_righted() {
+	let mut app = test_app(None);
+
+	// lying along X, with its +X end raised
+	let tilt = -FRAC_PI_2 + 0.2;
+	let volume = BuoyancyVolume::capsule(0.5, 2.0);
+	let child = spawn_tilted(&mut app, Collider::capsule(4.0, 0.5), tilt, volume);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let expected = Vec3::Y
+		* weight_of_water(
+			&app,
+			BuoyancyShape::Capsule {
+				radius: 0.5,
+				half_height: 2.0,
+			}
+			.volume()
+				/ 2.0,
+		);
+	let force = buoyancy(&mut app, child);
+	assert!(
+		force.distance(expected) < expected.length() * 0.01,
+		"{force} != {expected}"
+	);
+
+	let torque = get::<InternalTorque>(child)(&mut app.world).compute_naive_torque();
+	assert!(torque.z < -1000.0, "{torque}");
+}
+
+#[test]
+fn tilted_cuboid_floats_level() {
+	let mut app = test_app(None);
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(60.0)));
+	app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+		1.0 / 60.0,
+	)));
+
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_rotation(Quat::from_rotation_z(0.4))),
+			RigidBody::Dynamic,
+			Collider::cuboid(4.0, 1.0, 2.0),
+			ColliderDensity(500.0),
+		))
+		.id();
+	app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			BuoyancyVolume::cuboid(Vec3::new(2.0, 0.5, 1.0)).with_drag(20000.0, 20000.0),
+		))
+		.set_parent(parent);
+
+	for _ in 0..600 {
+		app.update();
+	}
+
+	let up = get::<Rotation>(parent)(&mut app.world).0 * Vec3::Y;
+	assert!(up.angle_between(Vec3::Y) < 0.02, "tilted to {up}");
+}
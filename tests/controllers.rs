@@ -0,0 +1,211 @@
+mod utils;
+use bevy_xpbd_3d::plugins::setup::Physics;
+use bevy_xpbd_3d_parenting::Pid;
+use utils::*;
+
+/// Runs exactly one physics step of 1/60 of a second per update
+fn deterministic_app() -> App {
+	let mut app = test_app(None);
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(60.0)));
+	app
+}
+
+fn spawn_parent(app: &mut App, gravity_scale: f32, controller: impl Bundle) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::cuboid(2.0, 1.0, 2.0),
+			GravityScale(gravity_scale),
+			controller,
+		))
+		.id()
+}
+
+fn spawn_child(app: &mut App, parent: Entity, translation: Vec3, force: Vec3, max: f32) {
+	app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_translation(translation)),
+			InternalForce::new_local(force),
+			AllocationLimits::new(-max, max),
+		))
+		.set_parent(parent);
+}
+
+/// Two children pushing along X on either side, which can turn the parent around Y
+fn spawn_yaw_children(app: &mut App, parent: Entity) {
+	spawn_child(app, parent, Vec3::Z, Vec3::X, 20.0);
+	spawn_child(app, parent, Vec3::NEG_Z, Vec3::X, 20.0);
+}
+
+fn run(app: &mut App, frames: usize) {
+	for _ in 0..frames {
+		app.update();
+	}
+}
+
+#[test]
+fn controllers_insert_thrust_allocator() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(
+		&mut app,
+		0.0,
+		HeadingHold::new(0.0, Pid::new(1.0, 0.0, 0.0)),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert!(app.world.entity(parent).contains::<ThrustAllocator>());
+}
+
+#[test]
+fn heading_hold_turns_to_target() {
+	let mut app = deterministic_app();
+
+	let target = 1.0;
+	let parent = spawn_parent(
+		&mut app,
+		0.0,
+		HeadingHold::new(target, Pid::new(10.0, 0.0, 5.0)),
+	);
+	spawn_yaw_children(&mut app, parent);
+
+	run(&mut app, 600);
+
+	let heading = HeadingHold::heading_of(get::<Rotation>(parent)(&mut app.world).0);
+	assert!((heading - target).abs() < 0.05, "{heading} != {target}");
+	let position = get::<Position>(parent)(&mut app.world).0;
+	assert!(position.length() < 0.05, "drifted to {position}");
+}
+
+#[test]
+fn attitude_hold_turns_to_target() {
+	let mut app = deterministic_app();
+
+	let target = Quat::from_rotation_y(-0.5);
+	let parent = spawn_parent(
+		&mut app,
+		0.0,
+		AttitudeHold::new(target, Pid::new(10.0, 0.0, 5.0)),
+	);
+	spawn_yaw_children(&mut app, parent);
+
+	run(&mut app, 600);
+
+	let rotation = get::<Rotation>(parent)(&mut app.world).0;
+	assert!(rotation.angle_between(target) < 0.05);
+}
+
+#[test]
+fn altitude_hold_climbs_against_gravity() {
+	let mut app = deterministic_app();
+
+	let target = 2.0;
+	let parent = spawn_parent(
+		&mut app,
+		1.0,
+		AltitudeHold::new(target, Pid::new(20.0, 1.0, 10.0).with_max_integral(1.0)),
+	);
+	spawn_child(&mut app, parent, Vec3::ZERO, Vec3::Y, 200.0);
+
+	run(&mut app, 600);
+
+	let height = get::<Position>(parent)(&mut app.world).y;
+	assert!((height - target).abs() < 0.05, "{height} != {target}");
+}
+
+#[test]
+fn velocity_hold_reaches_target() {
+	let mut app = deterministic_app();
+
+	let target = Vec3::X * 3.0;
+	let parent = spawn_parent(
+		&mut app,
+		0.0,
+		VelocityHold::new(target, Pid::new(10.0, 0.0, 0.0)),
+	);
+	spawn_child(&mut app, parent, Vec3::ZERO, Vec3::X, 50.0);
+
+	run(&mut app, 300);
+
+	let velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+	assert!(velocity.distance(target) < 0.05, "{velocity} != {target}");
+}
+
+#[test]
+fn controllers_follow_physics_clock() {
+	let mut app = deterministic_app();
+
+	// no children, so the error never shrinks
+	let parent = spawn_parent(
+		&mut app,
+		0.0,
+		VelocityHold::new(Vec3::X * 3.0, Pid::new(0.0, 1.0, 0.0)),
+	);
+
+	// one step of 1/60 of a second per frame
+	run(&mut app, 60);
+	let integral = get::<VelocityHold>(parent)(&mut app.world)
+		.pid
+		.get_integral();
+	assert!(integral.distance(Vec3::X * 3.0) < 0.001, "{integral}");
+
+	app.world.resource_mut::<Time<Physics>>().pause();
+	// bevy_xpbd still runs the step it had queued when it was paused
+	run(&mut app, 1);
+	let integral = get::<VelocityHold>(parent)(&mut app.world)
+		.pid
+		.get_integral();
+	run(&mut app, 30);
+	let paused_integral = get::<VelocityHold>(parent)(&mut app.world)
+		.pid
+		.get_integral();
+	assert_eq!(paused_integral, integral);
+}
+
+#[test]
+fn controllers_run_on_simulated_time() {
+	// four frames per physics step
+	let mut app = fixed_hz_app(50.0, 200.0);
+
+	// no children, so the error never shrinks
+	let parent = spawn_parent(
+		&mut app,
+		0.0,
+		VelocityHold::new(Vec3::X * 3.0, Pid::new(0.0, 1.0, 0.0)),
+	);
+
+	run(&mut app, 40);
+
+	let steps = app.world.resource::<PhysicsSteps>().0;
+	assert!((10..40).contains(&steps), "{steps} steps");
+	let expected = Vec3::X * 3.0 * steps as f32 / 50.0;
+	let integral = get::<VelocityHold>(parent)(&mut app.world)
+		.pid
+		.get_integral();
+	assert!(
+		integral.distance(expected) < 0.001,
+		"{integral} != {expected}"
+	);
+}
+
+proptest! {
+	#[test]
+	fn pid_integral_is_clamped(
+		error in proptest::array::uniform3(-100f32..100.0),
+		max_integral in 0f32..10.0,
+		steps in 1usize..100,
+	) {
+		let mut pid = Pid::new(1.0, 1.0, 0.0).with_max_integral(max_integral);
+		for _ in 0..steps {
+			pid.update(Vec3::from(error), None, 0.1);
+		}
+
+		assert!(pid.get_integral().abs().max_element() <= max_integral);
+	}
+}
@@ -0,0 +1,228 @@
+mod utils;
+use bevy::ecs::event::ManualEventReader;
+use bevy_xpbd_3d_parenting::validate;
+use utils::*;
+
+fn read_diagnostics(
+	app: &App,
+	reader: &mut ManualEventReader<ParentingDiagnostic>,
+) -> Vec<ParentingIssue> {
+	let events = app.world.resource::<Events<ParentingDiagnostic>>();
+	reader
+		.read(events)
+		.map(|diagnostic| diagnostic.issue)
+		.collect()
+}
+
+fn spawn_parent(app: &mut App) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+		))
+		.id()
+}
+
+#[test]
+fn valid_setup_has_no_issues() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app);
+	app.world.entity_mut(parent).with_children(|parent| {
+		parent.spawn((
+			TransformBundle::default(),
+			InternalForce::new_local(Vec3::Y * 10.0),
+		));
+		parent.spawn((
+			TransformBundle::default(),
+			InternalTorque::new_global(Vec3::Y * 10.0),
+		));
+	});
+
+	let mut reader = ManualEventReader::default();
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert_eq!(validate(&app.world), vec![]);
+	assert!(app.world.resource::<ParentingDiagnostics>().is_empty());
+	assert_eq!(read_diagnostics(&app, &mut reader), vec![]);
+}
+
+#[test]
+fn orphan_child_is_reported_once() {
+	let mut app = test_app(None);
+
+	let child = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			InternalForce::new_global(Vec3::Y * 10.0),
+		))
+		.id();
+	let issue = ParentingIssue::NoRigidBodyAncestor { child };
+
+	// events are only kept for a couple of frames, so are read every frame
+	let mut reader = ManualEventReader::default();
+	let mut reported = Vec::new();
+	for _ in 0..5 {
+		app.update();
+		reported.extend(read_diagnostics(&app, &mut reader));
+	}
+
+	assert_eq!(validate(&app.world), vec![issue]);
+	assert!(app
+		.world
+		.resource::<ParentingDiagnostics>()
+		.contains(&issue));
+	assert_eq!(reported, vec![issue]);
+
+	// fixing the issue removes it
+	let parent = spawn_parent(&mut app);
+	app.world.entity_mut(child).set_parent(parent);
+	app.update();
+
+	assert_eq!(validate(&app.world), vec![]);
+	assert!(app.world.resource::<ParentingDiagnostics>().is_empty());
+	assert_eq!(read_diagnostics(&app, &mut reader), vec![]);
+}
+
+#[test]
+fn child_rigid_body_is_reported() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app);
+	let child = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			InternalForce::new_global(Vec3::Y * 10.0),
+		))
+		.set_parent(parent)
+		.id();
+
+	let mut reader = ManualEventReader::default();
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let issue = ParentingIssue::ChildIsRigidBody { child };
+	assert_eq!(validate(&app.world), vec![issue]);
+	assert_eq!(read_diagnostics(&app, &mut reader), vec![issue]);
+}
+
+#[test]
+fn missing_center_of_mass_is_reported() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app);
+	let child = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			InternalForce::new_global(Vec3::Y * 10.0),
+		))
+		.set_parent(parent)
+		.id();
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	app.world.entity_mut(parent).remove::<CenterOfMass>();
+	app.update();
+
+	let issue = ParentingIssue::MissingCenterOfMass { child, parent };
+	assert_eq!(validate(&app.world), vec![issue]);
+	assert!(app
+		.world
+		.resource::<ParentingDiagnostics>()
+		.contains(&issue));
+}
+
+#[test]
+fn persistent_external_impulse_is_reported() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app);
+	app
+		.world
+		.entity_mut(parent)
+		.insert(ExternalImpulse::ZERO.with_persistence(true));
+	let child = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			InternalImpulse::new_global(Vec3::Y * 10.0),
+		))
+		.set_parent(parent)
+		.id();
+
+	let mut reader = ManualEventReader::default();
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let issue = ParentingIssue::PersistentExternalImpulse { child, parent };
+	assert_eq!(validate(&app.world), vec![issue]);
+	assert_eq!(read_diagnostics(&app, &mut reader), vec![issue]);
+}
+
+#[test]
+fn missing_frame_entity_is_reported() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app);
+	let frame = app.world.spawn_empty().id();
+	let child = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			InternalForce::new_in_frame_of(frame, Vec3::Y * 10.0),
+			InternalTorque::new_in_frame_of(frame, Vec3::Y * 10.0),
+		))
+		.set_parent(parent)
+		.id();
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert_eq!(
+		validate(&app.world),
+		vec![ParentingIssue::MissingFrameEntity { child, frame }]
+	);
+}
+
+#[test]
+fn missing_propellant_tank_is_reported() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app);
+	let tank = app.world.spawn_empty().id();
+	let thruster = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			Thruster::new(10.0).with_throttle(1.0),
+			PropellantFeed::new(tank, 300.0),
+		))
+		.set_parent(parent)
+		.id();
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert_eq!(
+		validate(&app.world),
+		vec![ParentingIssue::MissingPropellantTank { thruster, tank }]
+	);
+	assert_eq!(
+		get::<InternalWrench>(parent)(&mut app.world).force(),
+		Vec3::ZERO
+	);
+}
@@ -0,0 +1,178 @@
+mod utils;
+use utils::*;
+
+fn spawn_actuator(app: &mut App, bundle: impl Bundle) -> (Entity, Entity) {
+	let parent = spawn_parent(app, ());
+	let child = spawn_child(app, parent, Vec3::ZERO, bundle);
+	(parent, child)
+}
+
+#[test]
+fn applied_strength_lags_behind_command() {
+	let mut app = fixed_once_app();
+
+	let time_constant = 0.5;
+	let (parent, child) = spawn_actuator(
+		&mut app,
+		(
+			InternalForce::new_local(Vec3::Y * 10.0),
+			ActuatorDynamics::new(time_constant),
+		),
+	);
+
+	for _ in 0..10 {
+		app.update();
+	}
+
+	// every physics step closes the same fraction of the remaining gap
+	let before = get::<ActuatorDynamics>(child)(&mut app.world).get_applied();
+	assert!(before > 0.0 && before < 1.0);
+	app.update();
+	let after = get::<ActuatorDynamics>(child)(&mut app.world).get_applied();
+	let expected = before + (1.0 - before) * (1.0 - (-1.0 / 60.0 / time_constant).exp());
+	assert!((after - expected).abs() < 0.0001, "{after} != {expected}");
+
+	// the commanded strength is untouched, but the parent feels the strength applied before the step
+	app.update();
+	assert_eq!(
+		get::<InternalForce>(child)(&mut app.world).get_strength(),
+		1.0
+	);
+	let force = get::<InternalWrenchLedger>(parent)(&mut app.world).force();
+	assert!(force.distance(Vec3::Y * 10.0 * after) < 0.0001, "{force}");
+}
+
+#[test]
+fn rise_and_fall_rates_are_limited() {
+	let mut dynamics = ActuatorDynamics::new(0.0).with_rates(1.0, 2.0);
+
+	for _ in 0..5 {
+		dynamics.step(1.0, 0.1);
+	}
+	assert!((dynamics.get_applied() - 0.5).abs() < 0.0001);
+
+	dynamics.step(0.0, 0.1);
+	assert!((dynamics.get_applied() - 0.3).abs() < 0.0001);
+
+	// rising in magnitude, in the negative direction
+	let mut dynamics = ActuatorDynamics::new(0.0).with_rates(1.0, 2.0);
+	dynamics.step(-1.0, 0.1);
+	assert!((dynamics.get_applied() + 0.1).abs() < 0.0001);
+}
+
+#[test]
+fn ignition_delay_and_min_level() {
+	let mut dynamics = ActuatorDynamics::new(0.0)
+		.with_min_level(0.2)
+		.with_ignition_delay(0.5);
+
+	dynamics.step(0.1, 0.25);
+	assert!(!dynamics.is_ignited());
+	assert_eq!(dynamics.get_applied(), 0.0);
+
+	// small commands are raised to the minimum on-level
+	dynamics.step(0.1, 0.25);
+	assert!(dynamics.is_ignited());
+	assert_eq!(dynamics.get_applied(), 0.2);
+
+	// shutting down means waiting for ignition again
+	dynamics.step(0.0, 0.1);
+	assert_eq!(dynamics.get_applied(), 0.0);
+	dynamics.step(0.5, 0.1);
+	assert!(!dynamics.is_ignited());
+	assert_eq!(dynamics.get_applied(), 0.0);
+
+	dynamics.snap_to(0.5);
+	assert!(dynamics.is_ignited());
+	assert_eq!(dynamics.get_applied(), 0.5);
+}
+
+#[test]
+fn thruster_waits_for_ignition_before_burning_propellant() {
+	let mut app = fixed_once_app();
+
+	let (parent, tank) = spawn_actuator(&mut app, PropellantTank::new(10.0));
+	let thruster = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			Thruster::new(1000.0).with_throttle(1.0),
+			PropellantFeed {
+				tank,
+				specific_impulse: 300.0,
+			},
+			ActuatorDynamics::new(0.0).with_ignition_delay(1.0),
+		))
+		.set_parent(parent)
+		.id();
+
+	for _ in 0..30 {
+		app.update();
+	}
+
+	assert!(!get::<ActuatorDynamics>(thruster)(&mut app.world).is_ignited());
+	assert_eq!(
+		get::<PropellantTank>(tank)(&mut app.world).get_propellant(),
+		10.0
+	);
+	assert_eq!(
+		get::<InternalWrenchLedger>(parent)(&mut app.world).force(),
+		Vec3::ZERO
+	);
+
+	for _ in 0..60 {
+		app.update();
+	}
+
+	assert!(get::<ActuatorDynamics>(thruster)(&mut app.world).is_ignited());
+	assert!(get::<PropellantTank>(tank)(&mut app.world).get_propellant() < 10.0);
+	let force = get::<InternalWrenchLedger>(parent)(&mut app.world).force();
+	assert!(force.distance(Vec3::Y * 1000.0) < 0.001, "{force}");
+}
+
+#[test]
+fn dry_thruster_ignites_again_after_refill() {
+	let mut app = fixed_once_app();
+
+	let (parent, tank) = spawn_actuator(&mut app, PropellantTank::new(10.0).with_propellant(0.0));
+	let thruster = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			Thruster::new(1000.0).with_throttle(1.0),
+			PropellantFeed::new(tank, 300.0),
+			ActuatorDynamics::new(0.0).with_ignition_delay(1.0),
+		))
+		.set_parent(parent)
+		.id();
+
+	for _ in 0..90 {
+		app.update();
+	}
+
+	// a dry thruster is commanded off, so never ignites
+	assert!(!get::<ActuatorDynamics>(thruster)(&mut app.world).is_ignited());
+	assert_eq!(
+		get::<InternalWrenchLedger>(parent)(&mut app.world).force(),
+		Vec3::ZERO
+	);
+
+	app.world.get_mut::<PropellantTank>(tank).unwrap().refill();
+	for _ in 0..30 {
+		app.update();
+	}
+
+	assert!(!get::<ActuatorDynamics>(thruster)(&mut app.world).is_ignited());
+	assert_eq!(
+		get::<InternalWrenchLedger>(parent)(&mut app.world).force(),
+		Vec3::ZERO
+	);
+
+	for _ in 0..60 {
+		app.update();
+	}
+
+	assert!(get::<ActuatorDynamics>(thruster)(&mut app.world).is_ignited());
+	let force = get::<InternalWrenchLedger>(parent)(&mut app.world).force();
+	assert!(force.distance(Vec3::Y * 1000.0) < 0.001, "{force}");
+}
@@ -0,0 +1,153 @@
+mod utils;
+use std::f32::consts::FRAC_PI_2;
+
+use utils::*;
+
+/// Spawns a parent with a single child, returning the parent
+fn spawn_with_child(app: &mut App, child: impl Bundle) -> Entity {
+	let mut parent = app.world.spawn((
+		TransformBundle::default(),
+		RigidBody::Dynamic,
+		ExternalForce::ZERO.with_persistence(false),
+		Collider::capsule(1.0, 1.0),
+		GravityScale(0.0),
+	));
+	parent.with_children(|parent| {
+		parent.spawn(child);
+	});
+	parent.id()
+}
+
+fn translation_after_update(app: &mut App, parent: Entity) -> Vec3 {
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	app.update();
+	get::<Transform>(parent)(&mut app.world).translation
+}
+
+/// Child rotated so that its local +Y is global -X
+fn rotated_child_transform() -> TransformBundle {
+	TransformBundle::from_transform(Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_2)))
+}
+
+#[test]
+fn parent_body_frame_ignores_child_rotation() {
+	let mut app = test_app(None);
+	let parent = spawn_with_child(
+		&mut app,
+		(
+			rotated_child_transform(),
+			InternalForce::new_parent_body(Vec3::Y * 100.0),
+		),
+	);
+
+	let translation = translation_after_update(&mut app, parent);
+	assert!(translation.y > 0.0, "Didn't move up: {:?}", translation);
+	assert!(
+		translation.x.abs() < translation.y,
+		"Moved sideways: {:?}",
+		translation
+	);
+}
+
+#[test]
+fn child_local_frame_includes_child_rotation() {
+	let mut app = test_app(None);
+	let parent = spawn_with_child(
+		&mut app,
+		(
+			rotated_child_transform(),
+			InternalForce::new_local(Vec3::Y * 100.0),
+		),
+	);
+
+	let translation = translation_after_update(&mut app, parent);
+	assert!(translation.x < 0.0, "Didn't move left: {:?}", translation);
+	assert!(
+		translation.y.abs() < -translation.x,
+		"Moved vertically: {:?}",
+		translation
+	);
+}
+
+#[test]
+fn entity_frame_uses_other_entity_rotation() {
+	let mut app = test_app(None);
+	// rotated so that its local +X is global +Y
+	let frame = app
+		.world
+		.spawn(TransformBundle::from_transform(Transform::from_rotation(
+			Quat::from_rotation_z(FRAC_PI_2),
+		)))
+		.id();
+	let parent = spawn_with_child(
+		&mut app,
+		(
+			TransformBundle::default(),
+			InternalForce::new_in_frame_of(frame, Vec3::X * 100.0),
+		),
+	);
+
+	let translation = translation_after_update(&mut app, parent);
+	assert!(translation.y > 0.0, "Didn't move up: {:?}", translation);
+	assert!(
+		translation.x.abs() < translation.y,
+		"Moved sideways: {:?}",
+		translation
+	);
+}
+
+#[test]
+fn missing_entity_frame_applies_nothing() {
+	let mut app = test_app(None);
+	let frame = app.world.spawn_empty().id();
+	app.world.despawn(frame);
+	let parent = spawn_with_child(
+		&mut app,
+		(
+			TransformBundle::default(),
+			InternalForce::new_in_frame_of(frame, Vec3::X * 100.0),
+		),
+	);
+
+	let translation = translation_after_update(&mut app, parent);
+	assert_eq!(translation, Vec3::ZERO);
+}
+
+#[test]
+fn parent_body_frame_uses_physics_rotation() {
+	let mut app = test_app(None);
+	let parent = spawn_with_child(
+		&mut app,
+		(
+			TransformBundle::default(),
+			InternalForce::new_parent_body(Vec3::X * 100.0),
+		),
+	);
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	// rotate the parent only in bevy_xpbd, so that its `GlobalTransform` lags behind
+	let rotation = Quat::from_rotation_z(FRAC_PI_2);
+	app.world.entity_mut(parent).insert(Rotation(rotation));
+	app.world.entity_mut(parent).insert(LinearVelocity::ZERO);
+	let get_parent_velocity = get::<LinearVelocity>(parent);
+	for _ in 0..1000 {
+		app.update();
+		if get_parent_velocity(&mut app.world).0 != Vec3::ZERO {
+			break;
+		}
+		std::thread::sleep(std::time::Duration::from_millis(1));
+	}
+
+	// local +X is now global +Y
+	let velocity = get_parent_velocity(&mut app.world).0;
+	assert!(velocity.y > 0.0, "Didn't move up: {:?}", velocity);
+	assert!(
+		velocity.x.abs() < velocity.y,
+		"Moved sideways: {:?}",
+		velocity
+	);
+}
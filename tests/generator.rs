@@ -0,0 +1,213 @@
+mod utils;
+use std::f32::consts::FRAC_PI_2;
+
+use bevy_xpbd_3d::plugins::setup::Physics;
+use bevy_xpbd_3d_parenting::{validate, ForceContext, ForceGenerator, ParentingIssue};
+use utils::*;
+
+/// Pushes with a constant force, at an offset from the child
+#[derive(Component)]
+struct Pusher {
+	force: Vec3,
+	point: Vec3,
+	frame: InternalFrame,
+}
+
+impl ForceGenerator for Pusher {
+	fn generate(&self, _context: &ForceContext) -> GeneratedWrench {
+		GeneratedWrench::new(self.frame)
+			.with_force(self.force)
+			.with_point(self.point)
+	}
+}
+
+/// Resists the motion of the child, like a drag chute
+#[derive(Component)]
+struct Drag(f32);
+
+impl ForceGenerator for Drag {
+	fn generate(&self, context: &ForceContext) -> GeneratedWrench {
+		GeneratedWrench::new(InternalFrame::World)
+			.with_force(-context.child_velocity * self.0)
+			.with_torque(-context.angular_velocity * self.0)
+	}
+}
+
+/// Pushes with its context's step length along X, and elapsed time along Y
+#[derive(Component)]
+struct Clock;
+
+impl ForceGenerator for Clock {
+	fn generate(&self, context: &ForceContext) -> GeneratedWrench {
+		GeneratedWrench::new(InternalFrame::World).with_force(Vec3::new(
+			context.delta_seconds,
+			context.elapsed_seconds,
+			0.0,
+		))
+	}
+}
+
+/// The [test_app], with the [Pusher], [Drag] and [Clock] generators registered
+fn generator_app() -> App {
+	let mut app = App::new();
+	app.add_plugins((
+		MinimalPlugins,
+		PhysicsPlugins::new(Update),
+		ParentingPlugin::new(Update)
+			.with_force_generator::<Pusher>()
+			.with_force_generator::<Drag>()
+			.with_force_generator::<Clock>(),
+	));
+	app
+}
+
+fn spawn_parent(app: &mut App, bundle: impl Bundle) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::sphere(1.0),
+			GravityScale(0.0),
+			bundle,
+		))
+		.id()
+}
+
+fn spawn_child(app: &mut App, parent: Entity, transform: Transform, bundle: impl Bundle) -> Entity {
+	app
+		.world
+		.spawn((TransformBundle::from_transform(transform), bundle))
+		.set_parent(parent)
+		.id()
+}
+
+#[test]
+fn generated_force_matches_internal_force() {
+	let mut app = generator_app();
+
+	let generated_parent = spawn_parent(&mut app, ());
+	let generated_child = spawn_child(
+		&mut app,
+		generated_parent,
+		Transform::default(),
+		Pusher {
+			force: Vec3::X * 10.0,
+			point: Vec3::Y,
+			frame: InternalFrame::World,
+		},
+	);
+	let internal_parent = spawn_parent(&mut app, ());
+	let internal_child = spawn_child(
+		&mut app,
+		internal_parent,
+		Transform::from_translation(Vec3::Y),
+		InternalForce::new_global(Vec3::X * 10.0),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let generated = get::<InternalWrenchLedger>(generated_parent)(&mut app.world);
+	let internal = get::<InternalWrenchLedger>(internal_parent)(&mut app.world);
+	let generated = generated.get(generated_child).unwrap();
+	let internal = internal.get(internal_child).unwrap();
+	assert_eq!(generated.force, internal.force);
+	assert_eq!(generated.point, internal.point);
+	assert_eq!(generated.torque, internal.torque);
+	assert_eq!(generated.torque, Vec3::NEG_Z * 10.0);
+	assert_eq!(
+		get::<GeneratedWrench>(generated_child)(&mut app.world).force,
+		Vec3::X * 10.0
+	);
+}
+
+#[test]
+fn generated_force_uses_child_frame() {
+	let mut app = generator_app();
+
+	let parent = spawn_parent(&mut app, ());
+	let child = spawn_child(
+		&mut app,
+		parent,
+		Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_2)),
+		Pusher {
+			force: Vec3::Y,
+			point: Vec3::ZERO,
+			frame: InternalFrame::ChildLocal,
+		},
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let ledger = get::<InternalWrenchLedger>(parent)(&mut app.world);
+	let force = ledger.get(child).unwrap().force;
+	assert!(force.distance(Vec3::NEG_X) < 0.0001, "{force}");
+}
+
+#[test]
+fn context_has_child_velocity() {
+	let mut app = generator_app();
+
+	let parent = spawn_parent(
+		&mut app,
+		(LinearVelocity(Vec3::Y), AngularVelocity(Vec3::Y)),
+	);
+	let child = spawn_child(
+		&mut app,
+		parent,
+		Transform::from_translation(Vec3::Z),
+		Drag(2.0),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	// spinning about Y moves the child along X
+	let generated = get::<GeneratedWrench>(child)(&mut app.world);
+	assert!(generated.force.distance(Vec3::new(-2.0, -2.0, 0.0)) < 0.01);
+	assert!(generated.torque.distance(Vec3::NEG_Y * 2.0) < 0.01);
+}
+
+#[test]
+fn orphan_generator_is_reported() {
+	let mut app = generator_app();
+
+	let child = app
+		.world
+		.spawn((TransformBundle::default(), Drag(1.0)))
+		.id();
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert_eq!(
+		validate(&app.world),
+		vec![ParentingIssue::NoRigidBodyAncestor { child }]
+	);
+}
+
+#[test]
+fn context_follows_physics_clock() {
+	let mut app = generator_app();
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(120.0)));
+
+	let parent = spawn_parent(&mut app, ());
+	let child = spawn_child(&mut app, parent, Transform::default(), Clock);
+
+	// the physics clock advances after the generators run
+	for _ in 0..3 {
+		app.update();
+	}
+
+	let force = get::<GeneratedWrench>(child)(&mut app.world).force;
+	assert!(
+		force.distance(Vec3::new(1.0 / 120.0, 2.0 / 120.0, 0.0)) < 1e-6,
+		"{force}"
+	);
+}
@@ -0,0 +1,185 @@
+mod utils;
+use bevy_xpbd_3d::plugins::setup::Physics;
+use utils::*;
+
+fn spawn_gimballed_thruster(app: &mut App, gimbal: Gimbal) -> (Entity, Entity) {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+		))
+		.id();
+	let child = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			Thruster::new(10.0).with_throttle(1.0),
+			gimbal,
+		))
+		.set_parent(parent)
+		.id();
+	(parent, child)
+}
+
+#[test]
+fn gimbal_deflects_thrust() {
+	let mut app = test_app(None);
+
+	let angle = 0.3;
+	let mut gimbal = Gimbal::new(0.5, 1.0).with_command(Vec2::new(angle, 0.0));
+	gimbal.snap_to_command();
+	let (parent, child) = spawn_gimballed_thruster(&mut app, gimbal);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	// thrust along +Y, rotated around +X
+	let expected = Vec3::new(0.0, angle.cos(), angle.sin()) * 10.0;
+	let wrench = get::<InternalWrench>(parent)(&mut app.world);
+	assert!(
+		wrench.force().distance(expected) < 0.001,
+		"{} != {}",
+		wrench.force(),
+		expected
+	);
+	// the child itself isn't rotated
+	assert_eq!(
+		get::<Transform>(child)(&mut app.world).rotation,
+		Quat::IDENTITY
+	);
+}
+
+#[test]
+fn gimbal_respects_slew_rate() {
+	let mut app = test_app(None);
+	let delta = 0.1;
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(1.0 / delta as f64)));
+
+	let slew_rate = 0.5;
+	let command = Vec2::new(0.0, -0.4);
+	let visual = app.world.spawn(TransformBundle::default()).id();
+	let (_, child) = spawn_gimballed_thruster(
+		&mut app,
+		Gimbal::new(1.0, slew_rate)
+			.with_command(command)
+			.with_visual(visual),
+	);
+
+	let mut previous = Vec2::ZERO;
+	for _ in 0..20 {
+		app.update();
+		let deflection = get::<Gimbal>(child)(&mut app.world).get_deflection();
+		assert!(deflection.distance(previous) <= slew_rate * delta + 0.0001);
+		previous = deflection;
+	}
+
+	let gimbal = get::<Gimbal>(child)(&mut app.world);
+	assert!(gimbal.get_deflection().distance(command) < 0.0001);
+	let visual_rotation = get::<Transform>(visual)(&mut app.world).rotation;
+	assert!(visual_rotation.angle_between(gimbal.compute_rotation()) < 0.0001);
+}
+
+#[test]
+fn gimbal_holds_while_physics_is_paused() {
+	let mut app = test_app(None);
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(10.0)));
+
+	let (_, child) = spawn_gimballed_thruster(
+		&mut app,
+		Gimbal::new(1.0, 0.5).with_command(Vec2::new(0.4, 0.0)),
+	);
+	for _ in 0..3 {
+		app.update();
+	}
+	assert_ne!(
+		get::<Gimbal>(child)(&mut app.world).get_deflection(),
+		Vec2::ZERO
+	);
+
+	app.world.resource_mut::<Time<Physics>>().pause();
+	// bevy_xpbd still runs the step it had queued when it was paused
+	app.update();
+	let deflection = get::<Gimbal>(child)(&mut app.world).get_deflection();
+	for _ in 0..5 {
+		app.update();
+	}
+	assert_eq!(
+		get::<Gimbal>(child)(&mut app.world).get_deflection(),
+		deflection
+	);
+}
+
+#[test]
+fn gimbal_slews_on_simulated_time() {
+	// four frames per physics step
+	let mut app = fixed_hz_app(50.0, 200.0);
+
+	let slew_rate = 0.5;
+	let (_, child) = spawn_gimballed_thruster(
+		&mut app,
+		Gimbal::new(1.0, slew_rate).with_command(Vec2::new(0.8, 0.0)),
+	);
+
+	for _ in 0..40 {
+		app.update();
+	}
+
+	let steps = app.world.resource::<PhysicsSteps>().0;
+	assert!((10..40).contains(&steps), "{steps} steps");
+	let expected = slew_rate * steps as f32 / 50.0;
+	let deflection = get::<Gimbal>(child)(&mut app.world).get_deflection();
+	assert!(
+		(deflection.x - expected).abs() < 0.0001,
+		"{deflection} != {expected}"
+	);
+}
+
+#[test]
+fn gimbal_visual_keeps_its_rest_rotation() {
+	let mut app = test_app(None);
+
+	let rest = Quat::from_rotation_y(1.0);
+	let visual = app
+		.world
+		.spawn(TransformBundle::from_transform(Transform::from_rotation(
+			rest,
+		)))
+		.id();
+	let mut gimbal = Gimbal::new(1.0, 1.0)
+		.with_command(Vec2::new(0.3, 0.0))
+		.with_visual(visual);
+	gimbal.snap_to_command();
+	let (_, child) = spawn_gimballed_thruster(&mut app, gimbal);
+
+	for _ in 0..3 {
+		app.update();
+	}
+	let expected = rest * get::<Gimbal>(child)(&mut app.world).compute_rotation();
+	let rotation = get::<Transform>(visual)(&mut app.world).rotation;
+	assert!(rotation.angle_between(expected) < 0.0001);
+
+	// returning to the center restores the rest rotation
+	let mut gimbal = app.world.get_mut::<Gimbal>(child).unwrap();
+	gimbal.set_command(Vec2::ZERO);
+	gimbal.snap_to_command();
+	app.update();
+	let rotation = get::<Transform>(visual)(&mut app.world).rotation;
+	assert!(rotation.angle_between(rest) < 0.0001);
+}
+
+proptest! {
+	#[test]
+	fn command_is_limited_to_cone(
+		max_angle in 0f32..1.5,
+		x in -10f32..10.0,
+		y in -10f32..10.0,
+	) {
+		let gimbal = Gimbal::new(max_angle, 1.0).with_command(Vec2::new(x, y));
+
+		assert!(gimbal.get_command().length() <= max_angle + 0.0001);
+	}
+}
@@ -0,0 +1,106 @@
+mod utils;
+use std::f32::consts::FRAC_PI_2;
+
+use utils::*;
+
+/// Spawns parent -> intermediate -> grandchild, returning the parent
+fn spawn_nested(app: &mut App, intermediate: Transform, grandchild: impl Bundle) -> Entity {
+	let mut parent = app.world.spawn((
+		TransformBundle::default(),
+		RigidBody::Dynamic,
+		ExternalForce::ZERO.with_persistence(false),
+		Collider::capsule(1.0, 1.0),
+		GravityScale(0.0),
+	));
+	parent.with_children(|parent| {
+		parent
+			.spawn(TransformBundle::from_transform(intermediate))
+			.with_children(|intermediate| {
+				intermediate.spawn(grandchild);
+			});
+	});
+	parent.id()
+}
+
+#[test]
+fn grandchild_force_reaches_rigid_body() {
+	let mut app = test_app(None);
+
+	let parent = spawn_nested(
+		&mut app,
+		Transform::from_xyz(0.0, 2.0, 0.0),
+		(
+			TransformBundle::default(),
+			InternalForce::new_global(Vec3::Y * 100.0),
+		),
+	);
+	let get_parent_transform = get::<Transform>(parent);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	app.update();
+
+	assert!(get_parent_transform(&mut app.world).translation.y > 0.0);
+}
+
+#[test]
+fn grandchild_lever_arm_includes_intermediate_translation() {
+	let mut app = test_app(None);
+
+	// intermediate is to the right, so a downwards force rotates clockwise
+	let parent = spawn_nested(
+		&mut app,
+		Transform::from_xyz(5.0, 0.0, 0.0),
+		(
+			TransformBundle::default(),
+			InternalForce::new_local(-Vec3::Y * 100.0),
+		),
+	);
+	let get_parent_angular_velocity = get::<AngularVelocity>(parent);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	app.update();
+
+	let angular_velocity = get_parent_angular_velocity(&mut app.world).0;
+	assert!(
+		angular_velocity.z < 0.0,
+		"Parent isn't rotating clockwise: {:?}",
+		angular_velocity
+	);
+}
+
+#[test]
+fn grandchild_local_force_includes_intermediate_rotation() {
+	let mut app = test_app(None);
+
+	// intermediate is rotated so that local +X is global +Y
+	let parent = spawn_nested(
+		&mut app,
+		Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_2)),
+		(
+			TransformBundle::default(),
+			InternalForce::new_local(Vec3::X * 100.0),
+		),
+	);
+	let get_parent_transform = get::<Transform>(parent);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	app.update();
+
+	let translation = get_parent_transform(&mut app.world).translation;
+	assert!(
+		translation.y > 0.0,
+		"Parent didn't move up: {:?}",
+		translation
+	);
+	assert!(
+		translation.x.abs() < translation.y,
+		"Parent moved sideways: {:?}",
+		translation
+	);
+}
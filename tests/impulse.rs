@@ -0,0 +1,103 @@
+mod utils;
+use utils::*;
+
+/// Updates until the physics step has consumed the parent's [ExternalImpulse]
+fn update_until_applied(app: &mut App, parent: Entity) {
+	for _ in 0..1000 {
+		app.update();
+		if get::<ExternalImpulse>(parent)(&mut app.world).impulse() == Vec3::ZERO {
+			return;
+		}
+		std::thread::sleep(std::time::Duration::from_millis(1));
+	}
+	panic!("Physics never stepped");
+}
+
+#[test]
+fn impulse_is_applied_once_and_cleared() {
+	let mut app = test_app(None);
+
+	let mut parent = app.world.spawn((
+		TransformBundle::default(),
+		RigidBody::Dynamic,
+		Collider::capsule(1.0, 1.0),
+		GravityScale(0.0),
+	));
+
+	let mut child = None;
+	parent.with_children(|parent| {
+		child = Some(
+			parent
+				.spawn((
+					TransformBundle::default(),
+					InternalImpulse::new_global(Vec3::X * 10.0),
+				))
+				.id(),
+		);
+	});
+
+	let parent = parent.id();
+	let child = child.unwrap();
+	let get_parent_velocity = get::<LinearVelocity>(parent);
+	let get_child_impulse = get::<InternalImpulse>(child);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	assert!(get_child_impulse(&mut app.world).is_cleared());
+	update_until_applied(&mut app, parent);
+
+	let velocity = get_parent_velocity(&mut app.world).0;
+	assert!(velocity.x > 0.0, "Impulse not applied: {:?}", velocity);
+
+	// no more impulse, so velocity stays the same
+	app.update();
+	app.update();
+	assert_eq!(get_parent_velocity(&mut app.world).0, velocity);
+
+	// firing again adds to the velocity
+	app
+		.world
+		.entity_mut(child)
+		.get_mut::<InternalImpulse>()
+		.unwrap()
+		.fire(Vec3::X * 10.0);
+	app.update();
+	assert!(get_child_impulse(&mut app.world).is_cleared());
+	update_until_applied(&mut app, parent);
+	assert!(get_parent_velocity(&mut app.world).0.x > velocity.x);
+}
+
+#[test]
+fn child_with_its_own_external_impulse_still_kicks_parent() {
+	let mut app = test_app(None);
+
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+		))
+		.id();
+	// an `ExternalImpulse` on a child does nothing, but shouldn't stop its `InternalImpulse`
+	let child = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			InternalImpulse::new_global(Vec3::X * 10.0),
+			ExternalImpulse::ZERO,
+		))
+		.set_parent(parent)
+		.id();
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	assert!(get::<InternalImpulse>(child)(&mut app.world).is_cleared());
+	update_until_applied(&mut app, parent);
+
+	let velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+	assert!(velocity.x > 0.0, "Impulse not applied: {:?}", velocity);
+}
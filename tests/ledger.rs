@@ -0,0 +1,129 @@
+mod utils;
+use utils::*;
+
+fn spawn_parent(app: &mut App, translation: Vec3) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_translation(translation)),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+		))
+		.id()
+}
+
+fn spawn_child(app: &mut App, parent: Entity, translation: Vec3, bundle: impl Bundle) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_translation(translation)),
+			bundle,
+		))
+		.set_parent(parent)
+		.id()
+}
+
+#[test]
+fn ledger_lists_each_child() {
+	let mut app = test_app(None);
+
+	let parent_translation = Vec3::new(1.0, 2.0, 3.0);
+	let parent = spawn_parent(&mut app, parent_translation);
+	let left = spawn_child(
+		&mut app,
+		parent,
+		Vec3::NEG_X,
+		InternalForce::new_global(Vec3::Y * 10.0),
+	);
+	let right = spawn_child(
+		&mut app,
+		parent,
+		Vec3::X,
+		InternalForce::new_global(Vec3::Y * 5.0),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let ledger = get::<InternalWrenchLedger>(parent)(&mut app.world);
+	assert_eq!(ledger.contributions().len(), 2);
+
+	let left = ledger.get(left).unwrap();
+	assert_eq!(left.force, Vec3::Y * 10.0);
+	assert!(left.point.distance(parent_translation + Vec3::NEG_X) < 0.001);
+	assert!(left.torque.distance(Vec3::NEG_X.cross(Vec3::Y * 10.0)) < 0.001);
+
+	let right = ledger.get(right).unwrap();
+	assert_eq!(right.force, Vec3::Y * 5.0);
+	assert!(right.point.distance(parent_translation + Vec3::X) < 0.001);
+	assert!(right.torque.distance(Vec3::X.cross(Vec3::Y * 5.0)) < 0.001);
+
+	let wrench = get::<InternalWrench>(parent)(&mut app.world);
+	assert_eq!(ledger.force(), wrench.force());
+	assert!(ledger.torque().distance(wrench.torque()) < 0.001);
+}
+
+#[test]
+fn force_and_torque_from_one_child_are_merged() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app, Vec3::ZERO);
+	let child = spawn_child(
+		&mut app,
+		parent,
+		Vec3::ZERO,
+		(
+			InternalForce::new_global(Vec3::X * 2.0),
+			InternalTorque::new_global(Vec3::Z * 3.0),
+		),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let ledger = get::<InternalWrenchLedger>(parent)(&mut app.world);
+	assert_eq!(ledger.contributions().len(), 1);
+	let contribution = ledger.get(child).unwrap();
+	assert_eq!(contribution.force, Vec3::X * 2.0);
+	assert!(contribution.torque.distance(Vec3::Z * 3.0) < 0.001);
+}
+
+#[test]
+fn removed_children_leave_the_ledger() {
+	let mut app = test_app(None);
+
+	let parent = spawn_parent(&mut app, Vec3::ZERO);
+	let kept = spawn_child(
+		&mut app,
+		parent,
+		Vec3::Y,
+		InternalForce::new_global(Vec3::X),
+	);
+	let removed = spawn_child(
+		&mut app,
+		parent,
+		Vec3::NEG_Y,
+		InternalForce::new_global(Vec3::X),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	assert_eq!(
+		get::<InternalWrenchLedger>(parent)(&mut app.world)
+			.contributions()
+			.len(),
+		2
+	);
+
+	app.world.entity_mut(removed).remove::<InternalForce>();
+	app.update();
+
+	let ledger = get::<InternalWrenchLedger>(parent)(&mut app.world);
+	assert_eq!(ledger.contributions().len(), 1);
+	assert!(ledger.get(kept).is_some());
+	assert!(ledger.get(removed).is_none());
+}
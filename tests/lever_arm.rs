@@ -0,0 +1,121 @@
+mod utils;
+use std::f32::consts::TAU;
+
+use utils::*;
+
+/// Spawns a parent with a single child, returning the parent
+fn spawn_with_child(app: &mut App, parent: Transform, child: impl Bundle) -> Entity {
+	let mut parent = app.world.spawn((
+		TransformBundle::from_transform(parent),
+		RigidBody::Dynamic,
+		ExternalForce::ZERO.with_persistence(false),
+		Collider::capsule(1.0, 1.0),
+		GravityScale(0.0),
+	));
+	parent.with_children(|parent| {
+		parent.spawn(child);
+	});
+	parent.id()
+}
+
+fn angular_velocity_after_update(app: &mut App, parent: Entity) -> Vec3 {
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	app.update();
+	get::<AngularVelocity>(parent)(&mut app.world).0
+}
+
+proptest! {
+	#[test]
+	fn torque_rotates_with_parent_about_z(rot in 0.0f32 .. 1.0f32) {
+		let mut app = test_app(None);
+
+		// child is to the right of the parent and pushes down (in the parent's frame),
+		// so should always rotate the parent clockwise
+		let parent = spawn_with_child(
+			&mut app,
+			Transform::from_rotation(Quat::from_rotation_z(TAU * rot)),
+			(
+				TransformBundle::from_transform(Transform::from_xyz(3.0, 0.0, 0.0)),
+				InternalForce::new_parent_body(-Vec3::Y * 100.0),
+			),
+		);
+
+		let angular_velocity = angular_velocity_after_update(&mut app, parent);
+		assert!(angular_velocity.z < 0.0, "Parent isn't rotating clockwise: {:?}", angular_velocity);
+	}
+
+	#[test]
+	fn torque_rotates_with_parent_about_y(rot in 0.0f32 .. 1.0f32) {
+		let mut app = test_app(None);
+
+		let parent_rotation = Quat::from_rotation_y(TAU * rot);
+		let parent = spawn_with_child(
+			&mut app,
+			Transform::from_rotation(parent_rotation),
+			(
+				TransformBundle::from_transform(Transform::from_xyz(3.0, 0.0, 0.0)),
+				InternalForce::new_parent_body(-Vec3::Y * 100.0),
+			),
+		);
+
+		// the torque is clockwise in the parent's frame, so is rotated by the parent's rotation
+		let expected_axis = parent_rotation.mul_vec3(-Vec3::Z);
+		let angular_velocity = angular_velocity_after_update(&mut app, parent);
+		assert!(
+			angular_velocity.normalize().dot(expected_axis) > 0.99,
+			"Parent isn't rotating about {:?}: {:?}",
+			expected_axis,
+			angular_velocity
+		);
+	}
+}
+
+#[test]
+fn lever_arm_includes_parent_scale() {
+	let mut app = test_app(None);
+
+	// both parents have the same mass properties, so a parent scaled by 2 with a child 1 unit away
+	// should behave exactly like an unscaled parent with a child 2 units away
+	let spawn = |app: &mut App, parent: Transform, child: Transform| {
+		let mut parent = app.world.spawn((
+			TransformBundle::from_transform(parent),
+			RigidBody::Dynamic,
+			ExternalForce::ZERO.with_persistence(false),
+			MassPropertiesBundle::new_computed(&Collider::cuboid(2.0, 2.0, 2.0), 1.0),
+			GravityScale(0.0),
+		));
+		parent.with_children(|parent| {
+			parent.spawn((
+				TransformBundle::from_transform(child),
+				InternalForce::new_global(-Vec3::Y * 100.0),
+			));
+		});
+		parent.id()
+	};
+	let unscaled = spawn(
+		&mut app,
+		Transform::IDENTITY,
+		Transform::from_xyz(2.0, 0.0, 0.0),
+	);
+	let scaled = spawn(
+		&mut app,
+		Transform::from_xyz(10.0, 0.0, 0.0).with_scale(Vec3::splat(2.0)),
+		Transform::from_xyz(1.0, 0.0, 0.0),
+	);
+
+	let scaled = angular_velocity_after_update(&mut app, scaled);
+	let unscaled = get::<AngularVelocity>(unscaled)(&mut app.world).0;
+	assert!(
+		unscaled.z < 0.0,
+		"Parent isn't rotating clockwise: {:?}",
+		unscaled
+	);
+	assert!(
+		(scaled - unscaled).length() < 1e-4,
+		"unscaled: {:?}, scaled: {:?}",
+		unscaled,
+		scaled
+	);
+}
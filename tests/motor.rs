@@ -0,0 +1,193 @@
+mod utils;
+use bevy_xpbd_3d::plugins::setup::Physics;
+use utils::*;
+
+fn spawn_motorised(
+	app: &mut App,
+	parent: impl Bundle,
+	offset: Vec3,
+	motor: VelocityMotor,
+) -> (Entity, Entity) {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::cuboid(1.0, 1.0, 1.0),
+			parent,
+		))
+		.id();
+	let child = spawn_child(app, parent, offset, motor);
+	(parent, child)
+}
+
+#[test]
+fn elevator_holds_speed_against_gravity() {
+	let mut app = fixed_once_app();
+
+	let (parent, child) = spawn_motorised(&mut app, (), Vec3::ZERO, VelocityMotor::new(1.0, 1000.0));
+
+	for _ in 0..30 {
+		app.update();
+	}
+
+	let velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+	assert!(velocity.distance(Vec3::Y) < 0.001, "{velocity}");
+
+	let mass = get::<Mass>(parent)(&mut app.world).0;
+	let motor = get::<VelocityMotor>(child)(&mut app.world);
+	assert!((motor.get_force() - mass * 9.81).abs() < 0.01);
+	assert!(!motor.is_saturated());
+}
+
+#[test]
+fn force_is_limited() {
+	let mut app = fixed_once_app();
+
+	let (parent, child) = spawn_motorised(
+		&mut app,
+		GravityScale(0.0),
+		Vec3::ZERO,
+		VelocityMotor::new(100.0, 0.5).with_axis(Vec3::X),
+	);
+
+	for _ in 0..60 {
+		app.update();
+	}
+
+	let mass = get::<Mass>(parent)(&mut app.world).0;
+	let velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+	// about a second at the maximum force
+	let expected = 0.5 / mass;
+	assert!(
+		velocity.x > expected * 0.95 && velocity.x < expected * 1.001,
+		"{velocity} {expected}"
+	);
+
+	let motor = get::<VelocityMotor>(child)(&mut app.world);
+	assert!(motor.is_saturated());
+	assert_eq!(motor.get_force(), 0.5);
+}
+
+#[test]
+fn offset_motor_drives_its_own_point() {
+	let mut app = fixed_once_app();
+
+	let offset = Vec3::new(1.0, 0.0, 0.5);
+	let (parent, _) = spawn_motorised(
+		&mut app,
+		GravityScale(0.0),
+		offset,
+		VelocityMotor::new(2.0, 1000.0),
+	);
+
+	for _ in 0..10 {
+		app.update();
+	}
+
+	let linear_velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+	let angular_velocity = get::<AngularVelocity>(parent)(&mut app.world).0;
+	let rotation = get::<Rotation>(parent)(&mut app.world).0;
+	// the parent also spins, so the motor's point moves faster than its centre
+	assert!(angular_velocity.length() > 0.1, "{angular_velocity}");
+
+	let lever_arm = rotation * offset;
+	let point_velocity = linear_velocity + angular_velocity.cross(lever_arm);
+	let axis = rotation * Vec3::Y;
+	assert!(
+		(point_velocity.dot(axis) - 2.0).abs() < 0.05,
+		"{point_velocity} {axis}"
+	);
+}
+
+#[test]
+fn motor_brakes_to_a_stop() {
+	let mut app = fixed_once_app();
+
+	let (parent, child) = spawn_motorised(
+		&mut app,
+		(GravityScale(0.0), LinearVelocity(Vec3::X * 5.0)),
+		Vec3::ZERO,
+		VelocityMotor::new(0.0, 1000.0).with_axis(Vec3::X),
+	);
+
+	for _ in 0..10 {
+		app.update();
+	}
+
+	let velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+	assert!(velocity.length() < 0.001, "{velocity}");
+	let motor = get::<VelocityMotor>(child)(&mut app.world);
+	assert!(motor.get_force().abs() < 0.001);
+}
+
+#[test]
+fn strength_scales_motor() {
+	let mut app = fixed_once_app();
+
+	let (_, child) = spawn_motorised(
+		&mut app,
+		GravityScale(0.0),
+		Vec3::ZERO,
+		VelocityMotor::new(100.0, 2.0),
+	);
+	app
+		.world
+		.entity_mut(child)
+		.insert(InternalForce::new_local(Vec3::ZERO).with_strength(0.5));
+
+	// the physics clock hasn't advanced before the first step
+	for _ in 0..SETUP_ITERATIONS + 1 {
+		app.update();
+	}
+
+	let force = get::<InternalForce>(child)(&mut app.world).compute_naive_force();
+	assert_eq!(force, Vec3::Y);
+}
+
+#[test]
+fn motor_reaches_target_within_one_physics_step() {
+	let mut app = test_app(None);
+	// much shorter than a frame
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(240.0)));
+
+	let (parent, _) = spawn_motorised(
+		&mut app,
+		(GravityScale(0.0), LinearVelocity(Vec3::X * 5.0)),
+		Vec3::ZERO,
+		VelocityMotor::new(1.0, 1000.0).with_axis(Vec3::X),
+	);
+
+	// the physics clock hasn't advanced before the first step
+	for _ in 0..SETUP_ITERATIONS + 1 {
+		app.update();
+	}
+
+	let velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+	assert!(velocity.distance(Vec3::X) < 0.001, "{velocity}");
+}
+
+#[test]
+fn motor_settles_with_several_steps_per_frame() {
+	// two physics steps per frame
+	let mut app = fixed_hz_app(120.0, 60.0);
+
+	let (parent, _) = spawn_motorised(
+		&mut app,
+		GravityScale(0.0),
+		Vec3::ZERO,
+		VelocityMotor::new(2.0, 1e6),
+	);
+
+	for _ in 0..10 {
+		app.update();
+	}
+	// the force is held for every step of the frame, so is sized to reach the target by its end
+	for _ in 0..10 {
+		app.update();
+		let velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+		assert!(velocity.distance(Vec3::Y * 2.0) < 0.001, "{velocity}");
+	}
+	let steps = app.world.resource::<PhysicsSteps>().0;
+	assert!(steps > 20, "{steps} steps");
+}
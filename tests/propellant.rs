@@ -0,0 +1,111 @@
+mod utils;
+use bevy_xpbd_3d_parenting::STANDARD_GRAVITY;
+use utils::*;
+
+#[test]
+fn tank_drains_at_specific_impulse_rate() {
+	let mut app = fixed_once_app();
+
+	let parent = spawn_parent(&mut app, ());
+	let tank = spawn_child(&mut app, parent, Vec3::ZERO, PropellantTank::new(10.0));
+	spawn_child(
+		&mut app,
+		parent,
+		Vec3::NEG_Y,
+		(
+			Thruster::new(100.0).with_throttle(1.0),
+			PropellantFeed::new(tank, 100.0),
+		),
+	);
+
+	let steps = 60;
+	for _ in 0..steps {
+		app.update();
+	}
+
+	let consumed = 10.0 - get::<PropellantTank>(tank)(&mut app.world).get_propellant();
+	let mass_flow = 100.0 / (100.0 * STANDARD_GRAVITY);
+	let expected = mass_flow * steps as f32 / 60.0;
+	assert!(
+		(consumed - expected).abs() <= mass_flow / 60.0 + 0.0001,
+		"consumed {consumed} kg, expected {expected} kg"
+	);
+}
+
+#[test]
+fn empty_tank_stops_thrust() {
+	let mut app = fixed_once_app();
+
+	let parent = spawn_parent(&mut app, ());
+	let tank = spawn_child(
+		&mut app,
+		parent,
+		Vec3::ZERO,
+		PropellantTank::new(1.0).with_propellant(0.0),
+	);
+	spawn_child(
+		&mut app,
+		parent,
+		Vec3::ZERO,
+		(
+			Thruster::new(100.0).with_throttle(1.0),
+			PropellantFeed::new(tank, 300.0),
+		),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	assert_eq!(
+		get::<InternalWrench>(parent)(&mut app.world).force(),
+		Vec3::ZERO
+	);
+
+	app
+		.world
+		.entity_mut(tank)
+		.get_mut::<PropellantTank>()
+		.unwrap()
+		.refill();
+	app.update();
+
+	let wrench = get::<InternalWrench>(parent)(&mut app.world);
+	assert!(wrench.force().distance(Vec3::Y * 100.0) < 0.001);
+}
+
+#[test]
+fn propellant_mass_shifts_center_of_mass() {
+	let mut app = fixed_once_app();
+
+	let reference = spawn_parent(&mut app, ());
+	let parent = spawn_parent(&mut app, ());
+	let tank = spawn_child(&mut app, parent, Vec3::X * 2.0, PropellantTank::new(5.0));
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let dry_mass = get::<Mass>(reference)(&mut app.world).0;
+	let mass = get::<Mass>(parent)(&mut app.world).0;
+	assert!((mass - (dry_mass + 5.0)).abs() < 0.001);
+	let center_of_mass = get::<CenterOfMass>(parent)(&mut app.world).0;
+	let expected = Vec3::X * 2.0 * 5.0 / (dry_mass + 5.0);
+	assert!(
+		center_of_mass.distance(expected) < 0.001,
+		"{center_of_mass} != {expected}"
+	);
+
+	// burning all the propellant restores the original mass properties
+	app
+		.world
+		.entity_mut(tank)
+		.get_mut::<PropellantTank>()
+		.unwrap()
+		.set_propellant(0.0);
+	app.update();
+
+	let mass = get::<Mass>(parent)(&mut app.world).0;
+	assert!((mass - dry_mass).abs() < 0.001);
+	let center_of_mass = get::<CenterOfMass>(parent)(&mut app.world).0;
+	assert!(center_of_mass.length() < 0.001);
+}
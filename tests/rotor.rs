@@ -0,0 +1,212 @@
+mod utils;
+use bevy_xpbd_3d::plugins::setup::Physics;
+use utils::*;
+
+fn spawn_rotors(
+	app: &mut App,
+	rotors: impl IntoIterator<Item = (Vec3, Rotor)>,
+) -> (Entity, Vec<Entity>) {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::cuboid(1.0, 1.0, 1.0),
+			GravityScale(0.0),
+		))
+		.id();
+	let children = rotors
+		.into_iter()
+		.map(|(translation, rotor)| {
+			app
+				.world
+				.spawn((
+					TransformBundle::from_transform(Transform::from_translation(translation)),
+					rotor,
+				))
+				.set_parent(parent)
+				.id()
+		})
+		.collect();
+	(parent, children)
+}
+
+fn spinning(spin: SpinDirection, rpm: f32) -> Rotor {
+	Rotor::new(10.0, 0.2, 1000.0)
+		.with_spin(spin)
+		.with_spool_time(0.0)
+		.with_command_rpm(rpm)
+}
+
+#[test]
+fn rotor_thrust_and_reaction_torque() {
+	let mut app = test_app(None);
+
+	let (_, children) = spawn_rotors(
+		&mut app,
+		[
+			(
+				Vec3::ZERO,
+				spinning(SpinDirection::CounterClockwise, 1000.0),
+			),
+			(Vec3::ZERO, spinning(SpinDirection::Clockwise, 500.0)),
+		],
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let force = get::<InternalForce>(children[0])(&mut app.world).compute_naive_force();
+	let torque = get::<InternalTorque>(children[0])(&mut app.world).compute_naive_torque();
+	assert!(force.distance(Vec3::Y * 10.0) < 0.0001);
+	// counter-clockwise rotors twist their parent clockwise
+	assert!(torque.distance(Vec3::NEG_Y * 0.2) < 0.0001);
+
+	// half the RPM, a quarter of the thrust and torque
+	let force = get::<InternalForce>(children[1])(&mut app.world).compute_naive_force();
+	let torque = get::<InternalTorque>(children[1])(&mut app.world).compute_naive_torque();
+	assert!(force.distance(Vec3::Y * 2.5) < 0.0001);
+	assert!(torque.distance(Vec3::Y * 0.05) < 0.0001);
+}
+
+#[test]
+fn quadcopter_reaction_torques_cancel() {
+	let mut app = test_app(None);
+
+	let (parent, _) = spawn_rotors(
+		&mut app,
+		[
+			(
+				Vec3::new(1.0, 0.0, 1.0),
+				spinning(SpinDirection::CounterClockwise, 800.0),
+			),
+			(
+				Vec3::new(-1.0, 0.0, -1.0),
+				spinning(SpinDirection::CounterClockwise, 800.0),
+			),
+			(
+				Vec3::new(1.0, 0.0, -1.0),
+				spinning(SpinDirection::Clockwise, 800.0),
+			),
+			(
+				Vec3::new(-1.0, 0.0, 1.0),
+				spinning(SpinDirection::Clockwise, 800.0),
+			),
+		],
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let ledger = get::<InternalWrenchLedger>(parent)(&mut app.world);
+	assert!(ledger.force().distance(Vec3::Y * 4.0 * 10.0 * 0.64) < 0.001);
+	assert!(ledger.torque().length() < 0.001, "{}", ledger.torque());
+}
+
+#[test]
+fn rotor_spools_up_towards_command() {
+	let mut app = test_app(None);
+	let delta = 0.1;
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(1.0 / delta as f64)));
+
+	let spool_time = 0.5;
+	let (_, children) = spawn_rotors(
+		&mut app,
+		[(
+			Vec3::ZERO,
+			Rotor::new(10.0, 0.2, 1000.0)
+				.with_spool_time(spool_time)
+				.with_command_rpm(1000.0),
+		)],
+	);
+
+	let mut previous = 0.0;
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+		let rpm = get::<Rotor>(children[0])(&mut app.world).get_rpm();
+		assert!(rpm >= previous && rpm < 1000.0);
+		previous = rpm;
+	}
+
+	// every step closes the same fraction of the remaining gap
+	let before = get::<Rotor>(children[0])(&mut app.world).get_rpm();
+	app.update();
+	let after = get::<Rotor>(children[0])(&mut app.world).get_rpm();
+	let expected = before + (1000.0 - before) * (1.0 - (-delta / spool_time).exp());
+	assert!((after - expected).abs() < 0.01, "{after} != {expected}");
+
+	for _ in 0..100 {
+		app.update();
+	}
+	assert!((get::<Rotor>(children[0])(&mut app.world).get_rpm() - 1000.0).abs() < 0.01);
+}
+
+#[test]
+fn rotor_spools_on_simulated_time() {
+	// four frames per physics step
+	let mut app = fixed_hz_app(50.0, 200.0);
+
+	let spool_time = 0.5;
+	let (_, children) = spawn_rotors(
+		&mut app,
+		[(
+			Vec3::ZERO,
+			Rotor::new(10.0, 0.2, 1000.0)
+				.with_spool_time(spool_time)
+				.with_command_rpm(1000.0),
+		)],
+	);
+
+	for _ in 0..40 {
+		app.update();
+	}
+
+	let steps = app.world.resource::<PhysicsSteps>().0;
+	assert!((10..40).contains(&steps), "{steps} steps");
+	let simulated = steps as f32 / 50.0;
+	let expected = 1000.0 * (1.0 - (-simulated / spool_time).exp());
+	let rpm = get::<Rotor>(children[0])(&mut app.world).get_rpm();
+	assert!((rpm - expected).abs() < 0.1, "{rpm} != {expected}");
+}
+
+#[test]
+fn rotor_holds_rpm_while_physics_is_paused() {
+	let mut app = test_app(None);
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(10.0)));
+
+	let (_, children) = spawn_rotors(
+		&mut app,
+		[(
+			Vec3::ZERO,
+			Rotor::new(10.0, 0.2, 1000.0)
+				.with_spool_time(0.5)
+				.with_command_rpm(1000.0),
+		)],
+	);
+	for _ in 0..3 {
+		app.update();
+	}
+	assert!(get::<Rotor>(children[0])(&mut app.world).get_rpm() > 0.0);
+
+	app.world.resource_mut::<Time<Physics>>().pause();
+	// bevy_xpbd still runs the step it had queued when it was paused
+	app.update();
+	let rpm = get::<Rotor>(children[0])(&mut app.world).get_rpm();
+	for _ in 0..5 {
+		app.update();
+	}
+	assert_eq!(get::<Rotor>(children[0])(&mut app.world).get_rpm(), rpm);
+}
+
+#[test]
+fn rotor_command_is_clamped() {
+	let mut rotor = Rotor::new(10.0, 0.2, 1000.0);
+	rotor.set_command_rpm(5000.0);
+	assert_eq!(rotor.get_command_rpm(), 1000.0);
+	rotor.set_command_rpm(-10.0);
+	assert_eq!(rotor.get_command_rpm(), 0.0);
+	rotor.set_command_rpm(f32::NAN);
+	assert_eq!(rotor.get_command_rpm(), 0.0);
+}
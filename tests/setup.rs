@@ -0,0 +1,106 @@
+mod utils;
+use bevy::ecs::event::ManualEventReader;
+use bevy_xpbd_3d_parenting::ParentComponent;
+use utils::*;
+
+fn read_inserted(
+	app: &App,
+	reader: &mut ManualEventReader<ParentComponentsInserted>,
+) -> Vec<ParentComponentsInserted> {
+	let events = app.world.resource::<Events<ParentComponentsInserted>>();
+	reader.read(events).cloned().collect()
+}
+
+#[test]
+fn missing_parent_components_are_inserted() {
+	let mut app = test_app(None);
+
+	// no `ExternalForce` or `ExternalTorque`
+	let mut parent = app.world.spawn((
+		TransformBundle::default(),
+		RigidBody::Dynamic,
+		Collider::capsule(1.0, 1.0),
+	));
+	parent.with_children(|parent| {
+		parent.spawn((
+			TransformBundle::default(),
+			InternalForce::new_global(Vec3::Y * 10.0),
+		));
+		parent.spawn((
+			TransformBundle::default(),
+			InternalImpulse::new_global(Vec3::Y * 10.0),
+		));
+	});
+	let parent = parent.id();
+
+	let mut reader = ManualEventReader::default();
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let external_force = get::<ExternalForce>(parent)(&mut app.world);
+	assert!(!external_force.persistent);
+	let external_torque = get::<ExternalTorque>(parent)(&mut app.world);
+	assert!(!external_torque.persistent);
+	assert!(app.world.entity(parent).contains::<InternalWrench>());
+
+	let inserted = read_inserted(&app, &mut reader);
+	assert_eq!(
+		inserted,
+		vec![ParentComponentsInserted {
+			parent,
+			components: vec![
+				ParentComponent::ExternalForce,
+				ParentComponent::ExternalTorque,
+				ParentComponent::InternalWrench,
+				ParentComponent::InternalWrenchLedger,
+				ParentComponent::ExternalImpulse,
+			],
+		}]
+	);
+
+	// only reported once
+	app.update();
+	app.update();
+	assert_eq!(read_inserted(&app, &mut reader), vec![]);
+}
+
+#[test]
+fn existing_parent_components_are_kept() {
+	let mut app = test_app(None);
+
+	let user_force = ExternalForce::new(Vec3::X).with_persistence(true);
+	let mut parent = app.world.spawn((
+		TransformBundle::default(),
+		RigidBody::Dynamic,
+		user_force,
+		Collider::capsule(1.0, 1.0),
+		GravityScale(0.0),
+	));
+	parent.with_children(|parent| {
+		parent.spawn((
+			TransformBundle::default(),
+			InternalTorque::new_global(Vec3::Y * 10.0),
+		));
+	});
+	let parent = parent.id();
+
+	let mut reader = ManualEventReader::default();
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert_eq!(get::<ExternalForce>(parent)(&mut app.world), user_force);
+	let inserted = read_inserted(&app, &mut reader);
+	assert_eq!(
+		inserted,
+		vec![ParentComponentsInserted {
+			parent,
+			components: vec![
+				ParentComponent::ExternalTorque,
+				ParentComponent::InternalWrench,
+				ParentComponent::InternalWrenchLedger,
+			],
+		}]
+	);
+}
@@ -0,0 +1,89 @@
+mod utils;
+use std::f32::consts::FRAC_PI_2;
+
+use utils::*;
+
+fn spawn_thruster(app: &mut App, thruster: Thruster, rotation: Quat) -> (Entity, Entity) {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+		))
+		.id();
+	let child = app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_rotation(rotation)),
+			thruster,
+		))
+		.set_parent(parent)
+		.id();
+	(parent, child)
+}
+
+#[test]
+fn thruster_pushes_parent() {
+	let mut app = test_app(None);
+
+	// the thrust direction (local +Y) is rotated to -X
+	let (parent, child) = spawn_thruster(
+		&mut app,
+		Thruster::new(100.0).with_throttle(0.5),
+		Quat::from_rotation_z(FRAC_PI_2),
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let internal_force = get::<InternalForce>(child)(&mut app.world);
+	assert_eq!(internal_force.get_frame(), InternalFrame::ChildLocal);
+	assert_eq!(internal_force.compute_naive_force(), Vec3::Y * 50.0);
+
+	let wrench = get::<InternalWrench>(parent)(&mut app.world);
+	assert!(wrench.force().distance(Vec3::NEG_X * 50.0) < 0.001);
+}
+
+#[test]
+fn changing_throttle_changes_force() {
+	let mut app = test_app(None);
+
+	let (parent, child) = spawn_thruster(
+		&mut app,
+		Thruster::new(10.0).with_exhaust_direction(Vec3::Z),
+		Quat::IDENTITY,
+	);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+	assert_eq!(
+		get::<InternalWrench>(parent)(&mut app.world).force(),
+		Vec3::ZERO
+	);
+
+	app
+		.world
+		.entity_mut(child)
+		.get_mut::<Thruster>()
+		.unwrap()
+		.set_throttle(1.0);
+	app.update();
+
+	let wrench = get::<InternalWrench>(parent)(&mut app.world);
+	assert!(wrench.force().distance(Vec3::NEG_Z * 10.0) < 0.001);
+}
+
+proptest! {
+	#[test]
+	fn throttle_is_clamped(throttle in proptest::num::f32::ANY, max_thrust in -1000f32..1000.0) {
+		let thruster = Thruster::new(max_thrust).with_throttle(throttle);
+
+		assert!((0.0..=1.0).contains(&thruster.get_throttle()));
+		assert!(thruster.get_max_thrust() >= 0.0);
+		assert!(thruster.compute_thrust().length() <= thruster.get_max_thrust() + 0.001);
+	}
+}
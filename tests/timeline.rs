@@ -0,0 +1,276 @@
+mod utils;
+use std::time::Duration;
+
+use bevy_xpbd_3d::plugins::setup::Physics;
+use bevy_xpbd_3d_parenting::{ForceKeyframe, Interpolation};
+use utils::*;
+
+fn keyframe(time: f32, strength: f32, interpolation: Interpolation) -> ForceKeyframe {
+	ForceKeyframe {
+		time,
+		strength,
+		direction: None,
+		interpolation,
+	}
+}
+
+/// The [test_app], with an [AssetPlugin] for loading timelines
+fn asset_app() -> App {
+	let mut app = App::new();
+	app.add_plugins((
+		MinimalPlugins,
+		AssetPlugin::default(),
+		PhysicsPlugins::new(Update),
+		ParentingPlugin::new(Update),
+	));
+	// the timeline assets are registered when finishing the plugins, which `App::run` would do
+	app.finish();
+	app.cleanup();
+	app
+}
+
+fn spawn_player(app: &mut App, player: ForceTimelinePlayer) -> Entity {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+		))
+		.id();
+	app
+		.world
+		.spawn((TransformBundle::default(), player))
+		.set_parent(parent)
+		.id()
+}
+
+#[test]
+fn timeline_interpolation() {
+	let timeline = ForceTimeline::new(
+		[
+			keyframe(2.0, 1.0, Interpolation::Step),
+			keyframe(0.0, 0.0, Interpolation::Linear),
+			keyframe(4.0, 0.0, Interpolation::Smooth),
+			keyframe(1.0, 1.0, Interpolation::Smooth),
+			keyframe(6.0, 1.0, Interpolation::Linear),
+		],
+		false,
+	);
+	let strength = |elapsed| timeline.sample(elapsed).unwrap().strength;
+
+	assert_eq!(timeline.duration(), 6.0);
+	assert_eq!(strength(-1.0), 0.0);
+	assert_eq!(strength(0.5), 0.5);
+	// smooth between equal keyframes
+	assert_eq!(strength(1.5), 1.0);
+	// holds until the next keyframe
+	assert_eq!(strength(3.9), 1.0);
+	// eases in and out
+	assert_eq!(strength(5.0), 0.5);
+	assert!(strength(4.5) < 0.25);
+	// holds the last keyframe
+	assert_eq!(strength(10.0), 1.0);
+	assert!(timeline.is_finished(10.0));
+}
+
+#[test]
+fn looping_timeline_repeats() {
+	let timeline = ForceTimeline::new(
+		[
+			keyframe(0.0, 0.0, Interpolation::Linear),
+			keyframe(2.0, 1.0, Interpolation::Linear),
+		],
+		true,
+	);
+
+	assert_eq!(timeline.sample(1.0).unwrap().strength, 0.5);
+	assert_eq!(timeline.sample(5.0).unwrap().strength, 0.5);
+	assert!(!timeline.is_finished(5.0));
+	assert_eq!(ForceTimeline::default().sample(1.0), None);
+}
+
+#[test]
+fn directions_are_interpolated_and_held() {
+	let timeline = ForceTimeline::new(
+		[
+			ForceKeyframe {
+				direction: Some(Vec3::X),
+				..keyframe(0.0, 1.0, Interpolation::Linear)
+			},
+			ForceKeyframe {
+				direction: Some(Vec3::Y),
+				..keyframe(1.0, 1.0, Interpolation::Linear)
+			},
+			keyframe(2.0, 1.0, Interpolation::Linear),
+		],
+		false,
+	);
+
+	assert_eq!(
+		timeline.sample(0.5).unwrap().direction,
+		Some(Vec3::new(0.5, 0.5, 0.0))
+	);
+	// held by keyframes without a direction
+	assert_eq!(timeline.sample(1.5).unwrap().direction, Some(Vec3::Y));
+	assert_eq!(timeline.sample(3.0).unwrap().direction, Some(Vec3::Y));
+}
+
+#[test]
+fn player_drives_internal_force() {
+	let mut app = asset_app();
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(4.0)));
+
+	let timeline = app
+		.world
+		.resource_mut::<Assets<ForceTimeline>>()
+		.add(ForceTimeline::new(
+			[
+				ForceKeyframe {
+					direction: Some(Vec3::Z * 5.0),
+					..keyframe(0.0, 0.0, Interpolation::Linear)
+				},
+				keyframe(10.0, 1.0, Interpolation::Linear),
+			],
+			false,
+		));
+	let child = spawn_player(&mut app, ForceTimelinePlayer::new(timeline).with_speed(2.0));
+
+	for _ in 0..4 {
+		app.update();
+	}
+
+	let player = get::<ForceTimelinePlayer>(child)(&mut app.world);
+	let internal_force = get::<InternalForce>(child)(&mut app.world);
+	let elapsed = player.get_elapsed();
+	assert_eq!(elapsed, 4.0 * 0.25 * 2.0);
+	// sampled before the last step
+	assert_eq!(internal_force.get_strength(), (elapsed - 0.25 * 2.0) / 10.0);
+	assert_eq!(internal_force.get_naive_force(), Vec3::Z * 5.0);
+
+	// paused players hold their place
+	let mut player = player;
+	player.paused = true;
+	set::<ForceTimelinePlayer>(child)(&mut app.world, player.clone());
+	app.update();
+	assert_eq!(
+		get::<ForceTimelinePlayer>(child)(&mut app.world).get_elapsed(),
+		elapsed
+	);
+
+	// as do players while physics is paused,
+	// once bevy_xpbd has run the step it had queued when it was paused
+	player.paused = false;
+	set::<ForceTimelinePlayer>(child)(&mut app.world, player);
+	app.world.resource_mut::<Time<Physics>>().pause();
+	app.update();
+	let elapsed = get::<ForceTimelinePlayer>(child)(&mut app.world).get_elapsed();
+	app.update();
+	assert_eq!(
+		get::<ForceTimelinePlayer>(child)(&mut app.world).get_elapsed(),
+		elapsed
+	);
+}
+
+#[test]
+fn player_starts_with_first_keyframe() {
+	let mut app = asset_app();
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(60.0)));
+
+	// the physics clock has been running for a while
+	for _ in 0..10 {
+		app.update();
+	}
+
+	let timeline = app
+		.world
+		.resource_mut::<Assets<ForceTimeline>>()
+		.add(ForceTimeline::new(
+			[
+				keyframe(0.0, 0.7, Interpolation::Step),
+				keyframe(0.001, 0.0, Interpolation::Step),
+			],
+			false,
+		));
+	let child = spawn_player(&mut app, ForceTimelinePlayer::new(timeline));
+	app.update();
+
+	assert_eq!(
+		get::<InternalForce>(child)(&mut app.world).get_strength(),
+		0.7
+	);
+}
+
+#[test]
+fn player_follows_simulated_time() {
+	let mut app = asset_app();
+	// four frames per physics step
+	run_at_fixed_hz(&mut app, 50.0, 200.0);
+
+	let timeline = app
+		.world
+		.resource_mut::<Assets<ForceTimeline>>()
+		.add(ForceTimeline::new(
+			[
+				keyframe(0.0, 0.0, Interpolation::Linear),
+				keyframe(10.0, 1.0, Interpolation::Linear),
+			],
+			false,
+		));
+	let child = spawn_player(&mut app, ForceTimelinePlayer::new(timeline));
+
+	for _ in 0..40 {
+		app.update();
+	}
+
+	let steps = app.world.resource::<PhysicsSteps>().0;
+	assert!((10..40).contains(&steps), "{steps} steps");
+	let elapsed = get::<ForceTimelinePlayer>(child)(&mut app.world).get_elapsed();
+	let expected = steps as f32 / 50.0;
+	assert!(
+		(elapsed - expected).abs() < 0.0001,
+		"{elapsed} != {expected}"
+	);
+}
+
+#[test]
+fn timeline_loads_from_ron_file() {
+	let mut app = asset_app();
+
+	let timeline = app
+		.world
+		.resource::<AssetServer>()
+		.load::<ForceTimeline>("launch.timeline.ron");
+	spawn_player(&mut app, ForceTimelinePlayer::new(timeline.clone()));
+
+	for _ in 0..1000 {
+		app.update();
+		if app
+			.world
+			.resource::<Assets<ForceTimeline>>()
+			.contains(&timeline)
+		{
+			break;
+		}
+		std::thread::sleep(Duration::from_millis(1));
+	}
+
+	let timelines = app.world.resource::<Assets<ForceTimeline>>();
+	let timeline = timelines
+		.get(&timeline)
+		.expect("The timeline should have loaded");
+	assert_eq!(timeline.keyframes.len(), 5);
+	assert_eq!(timeline.duration(), 20.0);
+	assert_eq!(
+		timeline.sample(2.0).unwrap().direction,
+		Some(Vec3::Y * 1000.0)
+	);
+	assert_eq!(timeline.sample(2.0).unwrap().strength, 0.2);
+}
+
+#[test]
+fn invalid_ron_is_an_error() {
+	assert!(ForceTimeline::from_ron("(keyframes: [(time: 0.0)])").is_err());
+	assert!(ForceTimeline::from_ron("(keyframes: [(time: 0.0, strength: 1.0)])").is_ok());
+}
@@ -0,0 +1,78 @@
+mod utils;
+use std::f32::consts::TAU;
+
+use utils::*;
+
+proptest! {
+	#[test]
+	fn global_torque_spins_without_moving(torque in 1.0f32 .. 100.0f32) {
+		let mut app = test_app(None);
+
+		let mut parent = app.world.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			ExternalForce::ZERO.with_persistence(false),
+			// NB: Parent must have external torque that is NOT persistent!
+			ExternalTorque::ZERO.with_persistence(false),
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+		));
+
+		// child with internal torque, offset to make sure no force is produced
+		parent.with_children(|parent| {
+			parent.spawn((
+				TransformBundle::from_transform(Transform::from_xyz(3.0, 0.0, 0.0)),
+				InternalTorque::new_global(Vec3::Z * torque),
+			));
+		});
+
+		let parent = parent.id();
+		let get_parent_transform = get::<Transform>(parent);
+		let get_parent_angular_velocity = get::<AngularVelocity>(parent);
+
+		for _ in 0..SETUP_ITERATIONS {
+			app.update();
+		}
+		app.update();
+
+		assert_eq!(get_parent_transform(&mut app.world).translation, Vec3::ZERO);
+		let angular_velocity = get_parent_angular_velocity(&mut app.world).0;
+		assert!(angular_velocity.z > 0.0, "Parent hasn't spun: {:?}", angular_velocity);
+		assert!(angular_velocity.x.abs() < f32::EPSILON);
+		assert!(angular_velocity.y.abs() < f32::EPSILON);
+	}
+
+	#[test]
+	fn local_torque_factors_rotation_child(rot_child in 0.05f32 .. 0.2f32) {
+		let mut app = test_app(None);
+
+		let mut parent = app.world.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			ExternalForce::ZERO.with_persistence(false),
+			ExternalTorque::ZERO.with_persistence(false),
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+		));
+
+		// child rotated about Z, so local X torque has a world Y component
+		parent.with_children(|parent| {
+			parent.spawn((
+				TransformBundle::from_transform(Transform::from_rotation(Quat::from_rotation_z(TAU * rot_child))),
+				InternalTorque::new_local(Vec3::X * 10.0),
+			));
+		});
+
+		let parent = parent.id();
+		let get_parent_angular_velocity = get::<AngularVelocity>(parent);
+
+		for _ in 0..SETUP_ITERATIONS {
+			app.update();
+		}
+		app.update();
+
+		let angular_velocity = get_parent_angular_velocity(&mut app.world).0;
+		assert!(angular_velocity.x > 0.0, "Parent hasn't spun about X: {:?}", angular_velocity);
+		assert!(angular_velocity.y > 0.0, "Parent hasn't spun about Y: {:?}", angular_velocity);
+	}
+}
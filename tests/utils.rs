@@ -32,6 +32,77 @@ pub fn test_app(log_level: Option<&str>) -> App {
 	app
 }
 
+/// The number of physics steps that have run in a [fixed_hz_app]
+#[derive(Resource, Debug, Default)]
+pub struct PhysicsSteps(pub u32);
+
+/// A [test_app] running bevy_xpbd's default fixed timestep at `physics_hz`,
+/// while each update advances real time by `1 / frame_hz` seconds, see [run_at_fixed_hz]
+pub fn fixed_hz_app(physics_hz: f64, frame_hz: f64) -> App {
+	let mut app = test_app(None);
+	run_at_fixed_hz(&mut app, physics_hz, frame_hz);
+	app
+}
+
+/// Runs bevy_xpbd's default fixed timestep at `physics_hz`,
+/// while each update advances real time by `1 / frame_hz` seconds.
+/// The physics steps that actually ran are counted in [PhysicsSteps]
+pub fn run_at_fixed_hz(app: &mut App, physics_hz: f64, frame_hz: f64) {
+	app
+		.insert_resource(Time::new_with(
+			bevy_xpbd_3d::plugins::setup::Physics::fixed_hz(physics_hz),
+		))
+		.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+			std::time::Duration::from_secs_f64(1.0 / frame_hz),
+		))
+		.init_resource::<PhysicsSteps>()
+		.add_systems(
+			bevy_xpbd_3d::PhysicsSchedule,
+			(|mut steps: ResMut<PhysicsSteps>| steps.0 += 1)
+				.before(bevy_xpbd_3d::PhysicsStepSet::BroadPhase),
+		);
+}
+
+/// A [test_app] running exactly one physics step of a 60th of a second per update
+pub fn fixed_once_app() -> App {
+	let mut app = test_app(None);
+	app.insert_resource(Time::new_with(
+		bevy_xpbd_3d::plugins::setup::Physics::fixed_once_hz(60.0),
+	));
+	app
+}
+
+/// Spawns a dynamic [RigidBody] without gravity at the origin, with any extra components in `bundle`
+pub fn spawn_parent(app: &mut App, bundle: impl Bundle) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::default(),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			GravityScale(0.0),
+			bundle,
+		))
+		.id()
+}
+
+/// Spawns a child of `parent` at `translation` in its space, with the components in `bundle`
+pub fn spawn_child(
+	app: &mut App,
+	parent: Entity,
+	translation: Vec3,
+	bundle: impl Bundle,
+) -> Entity {
+	app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_translation(translation)),
+			bundle,
+		))
+		.set_parent(parent)
+		.id()
+}
+
 pub fn get<T: Component + Clone>(e: Entity) -> impl Fn(&mut World) -> T {
 	move |world| world.entity(e).get::<T>().unwrap().clone()
 }
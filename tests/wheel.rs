@@ -0,0 +1,226 @@
+mod utils;
+use std::f32::consts::FRAC_PI_2;
+use std::time::Duration;
+
+use bevy::time::TimeUpdateStrategy;
+use bevy_xpbd_3d::plugins::setup::Physics;
+use utils::*;
+
+/// The spatial query pipeline is first updated at the end of the first physics step,
+/// so wheels only find the ground on the frame after that
+const WHEEL_SETUP_ITERATIONS: u8 = SETUP_ITERATIONS + 1;
+
+/// A static floor, whose top surface is at `y = 0`
+fn spawn_ground(app: &mut App) {
+	app.world.spawn((
+		TransformBundle::from_transform(Transform::from_xyz(0.0, -0.5, 0.0)),
+		RigidBody::Static,
+		Collider::cuboid(100.0, 1.0, 100.0),
+	));
+}
+
+fn spawn_vehicle(
+	app: &mut App,
+	height: f32,
+	locked: bool,
+	density: f32,
+	wheels: impl IntoIterator<Item = (Vec3, RaycastWheel)>,
+) -> (Entity, Vec<Entity>) {
+	let mut parent = app.world.spawn((
+		TransformBundle::from_transform(Transform::from_xyz(0.0, height, 0.0)),
+		RigidBody::Dynamic,
+		// the wheels start inside of this, but ignore it
+		Collider::cuboid(2.0, 0.5, 4.0),
+		ColliderDensity(density),
+	));
+	if locked {
+		parent.insert(LockedAxes::ALL_LOCKED);
+	}
+	let parent = parent.id();
+	let children = wheels
+		.into_iter()
+		.map(|(translation, wheel)| {
+			app
+				.world
+				.spawn((
+					TransformBundle::from_transform(Transform::from_translation(translation)),
+					wheel,
+				))
+				.set_parent(parent)
+				.id()
+		})
+		.collect();
+	(parent, children)
+}
+
+fn wheel_force(app: &mut App, child: Entity) -> (Vec3, Vec3) {
+	(
+		get::<InternalForce>(child)(&mut app.world).compute_naive_force(),
+		get::<InternalTorque>(child)(&mut app.world).compute_naive_torque(),
+	)
+}
+
+#[test]
+fn suspension_holds_parent_up() {
+	let mut app = test_app(None);
+	spawn_ground(&mut app);
+
+	let (_, children) = spawn_vehicle(
+		&mut app,
+		1.0,
+		true,
+		1.0,
+		[(Vec3::ZERO, RaycastWheel::new(0.3, 1.0, 1000.0, 100.0))],
+	);
+
+	for _ in 0..WHEEL_SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let wheel = get::<RaycastWheel>(children[0])(&mut app.world);
+	let contact = wheel
+		.get_contact()
+		.expect("The wheel should touch the ground");
+	assert!((contact.compression - 0.3).abs() < 0.001);
+	assert!(contact.point.distance(Vec3::ZERO) < 0.001);
+	assert!((wheel.get_suspension_travel() - 0.7).abs() < 0.001);
+
+	let (force, torque) = wheel_force(&mut app, children[0]);
+	assert!(force.distance(Vec3::Y * 300.0) < 0.5, "{force}");
+	// the contact point is directly below the child
+	assert!(torque.length() < 0.01);
+}
+
+#[test]
+fn airborne_wheel_does_nothing() {
+	let mut app = test_app(None);
+	spawn_ground(&mut app);
+
+	let (_, children) = spawn_vehicle(
+		&mut app,
+		5.0,
+		true,
+		1.0,
+		[(Vec3::ZERO, RaycastWheel::new(0.3, 1.0, 1000.0, 100.0))],
+	);
+
+	for _ in 0..WHEEL_SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert!(!get::<RaycastWheel>(children[0])(&mut app.world).is_grounded());
+	assert_eq!(wheel_force(&mut app, children[0]), (Vec3::ZERO, Vec3::ZERO));
+}
+
+#[test]
+fn wheel_without_radius_is_skipped_and_reported() {
+	let mut app = test_app(None);
+	spawn_ground(&mut app);
+
+	let mut wheel = RaycastWheel::new(0.0, 1.0, 1000.0, 100.0);
+	wheel.drive_torque = 100.0;
+	wheel.brake_torque = 100.0;
+	let (parent, children) = spawn_vehicle(&mut app, 1.0, true, 1.0, [(Vec3::ZERO, wheel)]);
+
+	for _ in 0..WHEEL_SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert!(!get::<RaycastWheel>(children[0])(&mut app.world).is_grounded());
+	assert_eq!(wheel_force(&mut app, children[0]), (Vec3::ZERO, Vec3::ZERO));
+	let wrench = get::<InternalWrench>(parent)(&mut app.world);
+	assert!(wrench.force().is_finite() && wrench.torque().is_finite());
+	assert!(app
+		.world
+		.resource::<ParentingDiagnostics>()
+		.contains(&ParentingIssue::InvalidWheelRadius { wheel: children[0] }));
+}
+
+#[test]
+fn steered_wheel_drives_at_contact_point() {
+	let mut app = test_app(None);
+	spawn_ground(&mut app);
+
+	let mut wheel = RaycastWheel::new(0.5, 1.0, 1000.0, 100.0);
+	wheel.drive_torque = 50.0;
+	wheel.steering = FRAC_PI_2;
+	let (_, children) = spawn_vehicle(&mut app, 1.0, true, 1.0, [(Vec3::ZERO, wheel)]);
+
+	for _ in 0..WHEEL_SETUP_ITERATIONS {
+		app.update();
+	}
+
+	// steered a quarter turn left, from rolling along -Z to rolling along -X
+	let (force, torque) = wheel_force(&mut app, children[0]);
+	assert!(
+		force.distance(Vec3::new(-100.0, 500.0, 0.0)) < 0.5,
+		"{force}"
+	);
+	// the drive force is applied a meter below the child
+	let expected_torque = Vec3::NEG_Y.cross(Vec3::NEG_X * 100.0);
+	assert!(torque.distance(expected_torque) < 0.5, "{torque}");
+}
+
+#[test]
+fn drive_force_is_limited_by_friction() {
+	let mut app = test_app(None);
+	spawn_ground(&mut app);
+
+	let mut wheel = RaycastWheel::new(0.5, 1.0, 1000.0, 100.0).with_friction(0.5, 1.0);
+	wheel.drive_torque = 10000.0;
+	let (_, children) = spawn_vehicle(&mut app, 1.0, true, 1.0, [(Vec3::ZERO, wheel)]);
+
+	for _ in 0..WHEEL_SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let (force, _) = wheel_force(&mut app, children[0]);
+	assert!(
+		force.distance(Vec3::new(0.0, 500.0, -250.0)) < 0.5,
+		"{force}"
+	);
+}
+
+#[test]
+fn vehicle_settles_on_its_suspension() {
+	let mut app = test_app(None);
+	app.insert_resource(Time::new_with(Physics::fixed_once_hz(60.0)));
+	app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+		1.0 / 60.0,
+	)));
+	spawn_ground(&mut app);
+
+	let wheel = RaycastWheel::new(0.3, 0.5, 20000.0, 2000.0);
+	let (parent, children) = spawn_vehicle(
+		&mut app,
+		1.0,
+		false,
+		// a tonne in total
+		250.0,
+		[
+			(Vec3::new(1.0, 0.0, 2.0), wheel),
+			(Vec3::new(-1.0, 0.0, 2.0), wheel),
+			(Vec3::new(1.0, 0.0, -2.0), wheel),
+			(Vec3::new(-1.0, 0.0, -2.0), wheel),
+		],
+	);
+
+	for _ in 0..600 {
+		app.update();
+	}
+
+	let velocity = get::<LinearVelocity>(parent)(&mut app.world).0;
+	assert!(velocity.length() < 0.01, "still moving at {velocity}");
+
+	let weight = get::<Mass>(parent)(&mut app.world).0 * -app.world.resource::<Gravity>().0.y;
+	let load: f32 = children
+		.iter()
+		.map(|&child| {
+			get::<RaycastWheel>(child)(&mut app.world)
+				.get_contact()
+				.expect("Every wheel should touch the ground")
+				.load
+		})
+		.sum();
+	assert!((load - weight).abs() < weight * 0.01, "{load} != {weight}");
+}
@@ -0,0 +1,94 @@
+mod utils;
+use bevy_xpbd_3d_parenting::AirDensity;
+use utils::*;
+
+fn spawn_surface(app: &mut App, translation: Vec3, surface: AeroSurface) -> Entity {
+	let parent = app
+		.world
+		.spawn((
+			TransformBundle::from_transform(Transform::from_translation(translation)),
+			RigidBody::Dynamic,
+			Collider::capsule(1.0, 1.0),
+			LockedAxes::ALL_LOCKED,
+		))
+		.id();
+	app
+		.world
+		.spawn((TransformBundle::default(), surface))
+		.set_parent(parent)
+		.id()
+}
+
+fn force(app: &mut App, child: Entity) -> Vec3 {
+	get::<InternalForce>(child)(&mut app.world).compute_naive_force()
+}
+
+#[test]
+fn constant_wind_drags_stationary_surface() {
+	let mut app = test_app(None);
+	let wind = Vec3::Z * 10.0;
+	app.insert_resource(WindField::Constant(wind));
+
+	let surface = AeroSurface::new(2.0).with_drag(0.5, 0.0);
+	let child = spawn_surface(&mut app, Vec3::ZERO, surface.clone());
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	let density = app.world.resource::<AirDensity>().0;
+	let expected = surface.compute_force(Quat::IDENTITY, wind, density);
+	assert!(expected.z > 0.0);
+	assert!(force(&mut app, child).distance(expected) < 0.001);
+}
+
+#[test]
+fn wind_is_sampled_at_each_surface() {
+	let mut app = test_app(None);
+	// only blows on the positive X side of the world
+	app.insert_resource(WindField::from_fn(|position, _time| {
+		if position.x > 0.0 {
+			Vec3::Z * 10.0
+		} else {
+			Vec3::ZERO
+		}
+	}));
+
+	let surface = AeroSurface::new(2.0).with_drag(0.5, 0.0);
+	let windy = spawn_surface(&mut app, Vec3::X * 5.0, surface.clone());
+	let calm = spawn_surface(&mut app, Vec3::NEG_X * 5.0, surface);
+
+	for _ in 0..SETUP_ITERATIONS {
+		app.update();
+	}
+
+	assert!(force(&mut app, windy).z > 0.0);
+	assert_eq!(force(&mut app, calm), Vec3::ZERO);
+}
+
+#[test]
+fn gusts_travel_with_the_wind() {
+	let wind = WindField::Gusty {
+		mean: Vec3::X * 10.0,
+		gust: Vec3::X * 4.0,
+		period: 2.0,
+	};
+
+	assert!(wind.velocity_at(Vec3::ZERO, 0.0).distance(Vec3::X * 10.0) < 0.001);
+	// the peak of the gust
+	assert!(wind.velocity_at(Vec3::ZERO, 1.0).distance(Vec3::X * 14.0) < 0.001);
+	// reaches 10 meters downwind a second later
+	assert!(
+		wind
+			.velocity_at(Vec3::X * 10.0, 2.0)
+			.distance(Vec3::X * 14.0)
+			< 0.001
+	);
+	// and crosswind positions at the same time
+	assert!(
+		wind
+			.velocity_at(Vec3::Z * 10.0, 1.0)
+			.distance(Vec3::X * 14.0)
+			< 0.001
+	);
+}